@@ -31,7 +31,7 @@ async fn main() -> Result<()> {
     }
     
     // Create a project
-    let mut project = Project::new(
+    let project = Project::new(
         "Team Management Tool".to_string(),
         "AI-powered team management platform".to_string(),
     );
@@ -58,8 +58,8 @@ async fn main() -> Result<()> {
     metrics.active_members = 1;
     metrics.completed_tasks = 2;
     metrics.blockers_count = 0;
-    metrics.average_satisfaction = 8.5;
-    metrics.velocity = 75.0;
+    metrics.average_satisfaction = Some(8.5);
+    metrics.velocity = Some(75.0);
     
     println!("Created team metrics with health score: {:.1}", metrics.calculate_health_score());
     repo.save_team_metrics(&metrics)?;
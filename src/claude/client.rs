@@ -22,9 +22,9 @@ impl ClaudeClient {
             .arg("--model")
             .arg(&self.model)
             .arg("--max-tokens")
-            .arg(&self.max_tokens.to_string())
+            .arg(self.max_tokens.to_string())
             .arg("--temperature")
-            .arg(&self.temperature.to_string())
+            .arg(self.temperature.to_string())
             .arg(message)
             .output();
         
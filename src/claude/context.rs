@@ -1,4 +1,6 @@
 use crate::models::{Message, MessageRole};
+use crate::storage::{Storage, TeamRepository};
+use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -55,6 +57,18 @@ impl ConversationContext {
         }
     }
     
+    /// Creates a context for a member with `current_project` taken from
+    /// their largest active assignment.
+    pub fn for_member<S: Storage>(repo: &TeamRepository<S>, member_id: Uuid) -> Result<Self> {
+        let mut context = Self::new(Some(member_id));
+        context.context_data.current_project = repo
+            .get_projects_for_member(member_id, Utc::now().date_naive())?
+            .into_iter()
+            .next()
+            .map(|(project, _)| project.name);
+        Ok(context)
+    }
+    
     pub fn add_message(&mut self, role: MessageRole, content: String) {
         let message = Message {
             id: Uuid::new_v4(),
@@ -4,11 +4,13 @@ pub mod client;
 pub mod prompts;
 pub mod context;
 pub mod process;
+pub mod structured;
 
 pub use client::ClaudeClient;
 pub use prompts::PromptTemplate;
 pub use context::ConversationContext;
 pub use process::{ClaudeCliProcess, ClaudeCliManager};
+pub use structured::{Structured, StructuredResponse};
 
 pub async fn test_claude_connection() -> Result<()> {
     println!("Testing Claude CLI connection...");
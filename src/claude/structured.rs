@@ -0,0 +1,104 @@
+use anyhow::Result;
+use log::warn;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+/// A type Claude can be asked to return as JSON.
+pub trait StructuredResponse: DeserializeOwned {
+    /// JSON Schema the response must match; it is included in the prompt.
+    fn schema() -> String;
+
+    /// Checks beyond what deserializing already enforces.
+    fn validate(&self) -> std::result::Result<(), String> {
+        Ok(())
+    }
+}
+
+/// A response that parsed and validated, or the raw text when Claude did
+/// not manage to follow the schema.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Structured<T> {
+    Parsed(T),
+    Raw(String),
+}
+
+impl<T> Structured<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Structured<U> {
+        match self {
+            Structured::Parsed(value) => Structured::Parsed(f(value)),
+            Structured::Raw(text) => Structured::Raw(text),
+        }
+    }
+
+    pub fn parsed(self) -> Option<T> {
+        match self {
+            Structured::Parsed(value) => Some(value),
+            Structured::Raw(_) => None,
+        }
+    }
+}
+
+/// Adds instructions to answer with JSON matching `T`'s schema.
+pub fn json_prompt<T: StructuredResponse>(prompt: &str) -> String {
+    format!(
+        "{}\n\nRespond with only a JSON value, without code fences or any other text, \
+        matching this JSON Schema:\n{}",
+        prompt.trim_end(),
+        T::schema()
+    )
+}
+
+/// The JSON part of a response: the contents of a code fence if there is
+/// one, otherwise the span from the first `{` or `[` to the last `}` or `]`.
+pub fn extract_json(response: &str) -> &str {
+    let text = response.trim();
+    if let Some(start) = text.find("```") {
+        let fenced = &text[start + 3..];
+        let fenced = fenced.strip_prefix("json").unwrap_or(fenced);
+        if let Some(end) = fenced.find("```") {
+            return fenced[..end].trim();
+        }
+    }
+    match (text.find(['{', '[']), text.rfind(['}', ']'])) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => text,
+    }
+}
+
+/// Parses and validates a response, describing what was wrong on failure.
+pub fn parse<T: StructuredResponse>(response: &str) -> std::result::Result<T, String> {
+    let value: T = serde_json::from_str(extract_json(response)).map_err(|e| format!("invalid JSON: {}", e))?;
+    value.validate()?;
+    Ok(value)
+}
+
+/// Sends `prompt` with `T`'s schema attached. A reply that does not parse is
+/// retried once with the error appended; if that also fails the second
+/// reply is returned as raw text. Errors from `send` itself are returned.
+pub async fn request<T, F, Fut>(prompt: &str, mut send: F) -> Result<Structured<T>>
+where
+    T: StructuredResponse,
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<String>>,
+{
+    let prompt = json_prompt::<T>(prompt);
+    let response = send(prompt.clone()).await?;
+    let error = match parse::<T>(&response) {
+        Ok(value) => return Ok(Structured::Parsed(value)),
+        Err(error) => error,
+    };
+
+    warn!("Claude response did not match the schema ({}); retrying", error);
+    let retry = format!(
+        "{}\n\nYour previous answer could not be used: {}. Reply again with only the JSON.",
+        prompt, error
+    );
+    let response = send(retry).await?;
+    match parse::<T>(&response) {
+        Ok(value) => Ok(Structured::Parsed(value)),
+        Err(error) => {
+            warn!("Claude response still did not match the schema ({}); using the raw text", error);
+            Ok(Structured::Raw(response))
+        }
+    }
+}
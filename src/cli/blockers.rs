@@ -0,0 +1,200 @@
+use anyhow::{anyhow, bail, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::cli::AppContext;
+use crate::cli::member::find_member;
+use crate::cli::metrics::parse_age;
+use crate::cli::output::{self, OutputFormat};
+use crate::cli::project::find_project;
+use crate::dashboard::format_age;
+use crate::escalation::{self, EscalationTarget};
+use crate::models::{Blocker, BlockerSeverity, Escalation};
+use crate::services::{EmailService, SlackService};
+use crate::storage::resolver::MIN_ID_PREFIX;
+use crate::storage::RepositoryError;
+
+/// Looks a blocker up by id or id prefix.
+pub fn find_blocker(ctx: &AppContext, query: &str) -> Result<Blocker> {
+    let not_found = || RepositoryError::NotFound { kind: "blocker", query: query.to_string() };
+    let query = query.trim().to_lowercase();
+    if let Ok(id) = Uuid::parse_str(&query) {
+        return Ok(ctx.repo.get_blocker(id)?.ok_or_else(not_found)?);
+    }
+    if query.len() < MIN_ID_PREFIX {
+        bail!("blocker id prefix {:?} is too short; use at least {} characters", query, MIN_ID_PREFIX);
+    }
+
+    let mut found: Vec<Blocker> = ctx.repo.list_blockers()?
+        .into_iter()
+        .filter(|b| b.id.to_string().starts_with(&query))
+        .collect();
+    match found.len() {
+        0 => Err(not_found().into()),
+        1 => Ok(found.remove(0)),
+        n => bail!("{:?} matches {} blockers; use more of the id", query, n),
+    }
+}
+
+/// Member and project names by id, for display.
+fn names(ctx: &AppContext) -> Result<HashMap<Uuid, String>> {
+    let members = ctx.repo.list_team_members()?.into_iter().map(|m| (m.id, m.name));
+    let projects = ctx.repo.list_projects()?.into_iter().map(|p| (p.id, p.name));
+    Ok(members.chain(projects).collect())
+}
+
+pub async fn list(
+    ctx: &AppContext,
+    open: bool,
+    older_than: Option<String>,
+    member: Option<String>,
+    format: String,
+) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let older_than = older_than.as_deref().map(parse_age).transpose()?;
+    let member = member.map(|query| find_member(ctx, &query)).transpose()?;
+    let now = Utc::now();
+
+    let mut blockers = ctx.repo.list_blockers()?;
+    blockers.retain(|b| {
+        (!open || b.is_open())
+            && older_than.is_none_or(|age| b.age(now) >= age)
+            && member.as_ref().is_none_or(|m| b.is_reported_by(m.id) || b.owner_id == Some(m.id))
+    });
+
+    match format {
+        OutputFormat::Json => output::print_json(&blockers)?,
+        OutputFormat::Table => {
+            if blockers.is_empty() {
+                println!("No blockers found");
+                return Ok(());
+            }
+            let names = names(ctx)?;
+            let name = |id: &Uuid| names.get(id).cloned().unwrap_or_else(|| id.to_string()[..8].to_string());
+            let rows: Vec<Vec<String>> = blockers.iter().map(|b| {
+                let mut reporter = name(&b.reporter_id);
+                if !b.also_reported_by.is_empty() {
+                    reporter.push_str(&format!(" +{}", b.also_reported_by.len()));
+                }
+                let link = [b.project_id.as_ref().map(name), b.ticket.clone()]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                vec![
+                    b.id.to_string()[..8].to_string(),
+                    b.severity.to_string(),
+                    b.status.to_string(),
+                    format_age(b.age(now)),
+                    reporter,
+                    b.owner_id.as_ref().map(name).unwrap_or_else(|| "-".to_string()),
+                    if link.is_empty() { "-".to_string() } else { link },
+                    b.description.clone(),
+                ]
+            }).collect();
+            output::print_table(&["ID", "SEVERITY", "STATUS", "AGE", "REPORTED BY", "OWNER", "PROJECT", "DESCRIPTION"], &rows);
+        }
+    }
+    Ok(())
+}
+
+pub async fn resolve(ctx: &AppContext, blocker: String) -> Result<()> {
+    let found = find_blocker(ctx, &blocker)?;
+    if !found.is_open() {
+        bail!("blocker {} is already resolved", &found.id.to_string()[..8]);
+    }
+    let blocker = ctx.repo.resolve_blocker(found.id, Utc::now())?
+        .ok_or_else(|| anyhow!("blocker {} was removed", &found.id.to_string()[..8]))?;
+    println!("{} Resolved {} after {}", "✓".green(), blocker.description.bold(), format_age(blocker.age(Utc::now())));
+    Ok(())
+}
+
+pub async fn edit(
+    ctx: &AppContext,
+    blocker: String,
+    owner: Option<String>,
+    severity: Option<String>,
+    project: Option<String>,
+    ticket: Option<String>,
+) -> Result<()> {
+    let found = find_blocker(ctx, &blocker)?;
+    // Look everything up first so the update itself cannot fail half-way
+    let owner_id = owner.map(|owner| find_member(ctx, &owner)).transpose()?.map(|m| m.id);
+    let severity = severity.map(|s| s.parse::<BlockerSeverity>()).transpose()?;
+    let project_id = project.map(|project| find_project(ctx, &project)).transpose()?.map(|p| p.id);
+    let ticket = ticket.map(|t| t.trim().to_string());
+
+    let blocker = ctx.repo.update_blocker(found.id, |blocker| {
+        if let Some(owner_id) = owner_id {
+            blocker.owner_id = Some(owner_id);
+        }
+        if let Some(severity) = severity {
+            blocker.severity = severity;
+        }
+        if let Some(project_id) = project_id {
+            blocker.project_id = Some(project_id);
+        }
+        if let Some(ticket) = ticket {
+            blocker.ticket = (!ticket.is_empty()).then_some(ticket);
+        }
+    })?.ok_or_else(|| anyhow!("blocker {} was removed", &found.id.to_string()[..8]))?;
+    println!("{} Updated blocker {}: {}", "✓".green(), &blocker.id.to_string()[..8], blocker.description.bold());
+    Ok(())
+}
+
+/// Sends every blocker matching an escalation rule to the rule's targets,
+/// skipping anything already sent.
+pub async fn escalate(ctx: &AppContext, dry_run: bool) -> Result<()> {
+    let rules = &ctx.config.escalation.rules;
+    if rules.is_empty() {
+        println!("No escalation rules configured; add [[escalation.rules]] to the config file");
+        return Ok(());
+    }
+
+    // Slack messages and emails are sent through Claude CLI
+    if !dry_run && !ctx.config.claude.enabled {
+        bail!("Claude CLI integration is disabled, so escalations cannot be sent; enable [claude] or use --dry-run");
+    }
+
+    let now = Utc::now();
+    let pending = escalation::pending(&ctx.repo, rules, now)?;
+    if pending.is_empty() {
+        println!("{} Nothing to escalate", "✓".green());
+        return Ok(());
+    }
+
+    let names = names(ctx)?;
+    let mut failed = 0;
+    for item in &pending {
+        let message = escalation::message(item, &names, now);
+        let short_id = &item.blocker.id.to_string()[..8];
+        if dry_run {
+            println!("{} Would send {} to {} ({})", "!".yellow(), short_id, item.target, item.rule.name);
+            continue;
+        }
+
+        let sent = match &item.target {
+            EscalationTarget::Slack(channel) => SlackService::new().send_message(channel, &message).await,
+            EscalationTarget::Email(address) => {
+                let alert_type = format!("Blocker escalation: {}", item.rule.name);
+                EmailService::new().send_alert(std::slice::from_ref(address), &alert_type, &message).await
+            }
+        };
+        match sent {
+            Ok(_) => {
+                ctx.repo.save_escalation(&Escalation::new(item.blocker.id, item.rule.name.clone(), item.target.to_string(), Utc::now()))?;
+                println!("{} Sent {} to {} ({})", "✓".green(), short_id, item.target, item.rule.name);
+            }
+            Err(err) => {
+                failed += 1;
+                println!("{} Failed to send {} to {}: {:#}", "✗".red(), short_id, item.target, err);
+            }
+        }
+    }
+
+    if failed > 0 {
+        bail!("{} of {} escalation(s) failed; they will be retried on the next run", failed, pending.len());
+    }
+    Ok(())
+}
@@ -1,11 +1,15 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
+use std::collections::BTreeMap;
+use crate::claude::Structured;
+use crate::cli::output::{self, OutputFormat};
+use crate::cli::AppContext;
 use crate::models::TeamMember;
-use crate::storage::TeamRepository;
-use crate::claude::ClaudeCliManager;
-use crate::services::{JiraService, SlackService};
+use crate::services::{JiraService, JiraTicket, SlackService};
+use crate::storage::archive::{self, Archive, ImportMode, Resolution};
+use crate::storage::migrations::{self, CURRENT_SCHEMA_VERSION};
 
-pub async fn init(team_name: String, slack_channel: Option<String>) -> Result<()> {
+pub async fn init(_ctx: &AppContext, team_name: String, slack_channel: Option<String>) -> Result<()> {
     println!("{} Initializing team: {}", "✓".green(), team_name.bold());
     
     if let Some(channel) = slack_channel {
@@ -17,59 +21,37 @@ pub async fn init(team_name: String, slack_channel: Option<String>) -> Result<()
     Ok(())
 }
 
-pub async fn add_user(name: String, email: String, slack_id: Option<String>) -> Result<()> {
-    println!("{} Adding user: {} ({})", "✓".green(), name.bold(), email);
-    
-    // Initialize repository
-    let repo = TeamRepository::new("./data")?;
-    
+pub async fn add_user(ctx: &AppContext, name: String, email: String, role: String, slack_id: Option<String>) -> Result<()> {
     // Create team member
-    let mut member = TeamMember::new(name.clone(), email.clone(), "Team Member".to_string());
+    let mut member = TeamMember::new(name.trim().to_string(), email.trim().to_string(), role);
     
     if let Some(id) = slack_id {
-        println!("{} Slack ID: {}", "✓".green(), id.bold());
-        member = member.with_slack_id(id);
+        member = member.with_slack_id(id.trim().to_string());
     }
     
-    // Save to storage
-    repo.save_team_member(&member)?;
-    
-    println!("{} User added successfully! ID: {}", "✓".green(), member.id);
-    Ok(())
-}
-
-pub async fn status(mode: Option<String>, notify_slack: bool) -> Result<()> {
-    let mode_str = mode.unwrap_or_else(|| "interactive".to_string());
-    println!("{} Running status collection in {} mode", "✓".green(), mode_str.bold());
+    // Save to storage; validation and duplicate checks happen here
+    ctx.repo.save_team_member(&member)
+        .with_context(|| format!("could not add {}", member.name))?;
     
-    if notify_slack {
-        println!("{} Slack notifications enabled", "✓".green());
+    println!("{} Added user: {} ({}) as {}", "✓".green(), member.name.bold(), member.email, member.role);
+    if let Some(id) = &member.slack_id {
+        println!("{} Slack ID: {}", "✓".green(), id.bold());
     }
-    
-    // TODO: Implement status collection logic
-    println!("{} Status collection complete!", "✓".green());
+    println!("{} User added successfully! ID: {}", "✓".green(), member.id);
     Ok(())
 }
 
-pub async fn report(report_type: String, output: Option<String>) -> Result<()> {
-    println!("{} Generating {} report", "✓".green(), report_type.bold());
+pub async fn test_mcp(ctx: &AppContext) -> Result<()> {
+    println!("{} Testing Claude CLI connectivity...", "✓".green());
     
-    if let Some(output_path) = output {
-        println!("{} Output: {}", "✓".green(), output_path.bold());
+    if !ctx.config.claude.enabled {
+        println!("{} Claude CLI integration is disabled in the configuration", "✗".red());
+        return Ok(());
     }
     
-    // TODO: Implement report generation logic
-    println!("{} Report generation complete!", "✓".green());
-    Ok(())
-}
-
-pub async fn test_mcp() -> Result<()> {
-    println!("{} Testing Claude CLI connectivity...", "✓".green());
-    
-    let claude = ClaudeCliManager::get_instance().await?;
     let test_prompt = "Hello! This is a test of the Claude CLI integration. Please respond with a brief confirmation that you're working.";
     
-    match claude.send_prompt(&test_prompt).await {
+    match ctx.claude.send_prompt(test_prompt).await {
         Ok(response) => {
             println!("{} Claude CLI is working!", "✓".green());
             println!("Response: {}", response.lines().next().unwrap_or("No response"));
@@ -83,50 +65,180 @@ pub async fn test_mcp() -> Result<()> {
     Ok(())
 }
 
-pub async fn health() -> Result<()> {
+pub async fn health(ctx: &AppContext) -> Result<()> {
     println!("{} System Health Check", "✓".green());
     
     // Check storage
-    match TeamRepository::new("./data") {
-        Ok(repo) => {
-            let members = repo.list_team_members()?;
-            println!("  {} Storage: OK ({} team members)", "✓".green(), members.len());
+    let data_dir = ctx.config.app.data_path();
+    match ctx.repo.list_team_members() {
+        Ok(members) => {
+            println!("  {} Storage: OK ({} team members in {})", "✓".green(), members.len(), data_dir.display());
         }
-        Err(_) => {
-            println!("  {} Storage: Failed", "✗".red());
+        Err(e) => {
+            println!("  {} Storage: Failed ({}): {}", "✗".red(), data_dir.display(), e);
         }
     }
     
-    println!("  {} Configuration: OK", "✓".green());
-    println!("  {} Claude CLI: Ready for prompt-based integration", "✓".green());
+    println!("  {} Configuration: OK (team: {})", "✓".green(), ctx.config.team.name);
+    if ctx.config.claude.enabled {
+        println!("  {} Claude CLI: Ready for prompt-based integration", "✓".green());
+    } else {
+        println!("  {} Claude CLI: Disabled", "-".yellow());
+    }
     
     println!("{} System health check complete!", "✓".green());
     Ok(())
 }
 
-pub async fn query_jira(projects: Vec<String>, period: String) -> Result<()> {
-    println!("{} Querying Jira for work items...", "✓".green());
+pub async fn migrate(ctx: &AppContext, dry_run: bool) -> Result<()> {
+    let action = if dry_run { "Checking" } else { "Migrating" };
+    println!("{} {} data to schema version {}", "✓".green(), action, CURRENT_SCHEMA_VERSION);
+    
+    let reports = migrations::migrate_store(ctx.repo.store(), dry_run)?;
+    let pending = reports.iter().filter(|r| !r.is_up_to_date()).count();
+    
+    for report in &reports {
+        if report.is_up_to_date() {
+            println!("  {} {}: up to date (v{})", "✓".green(), report.key, report.to_version);
+            continue;
+        }
+        
+        println!("  {} {}: v{} -> v{}", "→".yellow(), report.key.bold(), report.from_version, report.to_version);
+        for description in &report.applied {
+            println!("      {}", description);
+        }
+        for change in &report.changes {
+            println!("      {}", change.to_string().dimmed());
+        }
+    }
+    
+    if pending == 0 {
+        println!("{} Nothing to migrate", "✓".green());
+    } else if dry_run {
+        println!("{} {} collection(s) would be migrated; run without --dry-run to apply", "!".yellow(), pending);
+    } else {
+        println!("{} Migrated {} collection(s)", "✓".green(), pending);
+    }
+    Ok(())
+}
+
+pub async fn export(ctx: &AppContext, output: Option<String>) -> Result<()> {
+    let archive = archive::export(ctx.repo.store())?;
+    let path = output.unwrap_or_else(|| {
+        format!("timeless-export-{}.json", archive.exported_at.format("%Y%m%d-%H%M%S"))
+    });
+    
+    let json = serde_json::to_string_pretty(&archive)?;
+    std::fs::write(&path, json).with_context(|| format!("failed to write {}", path))?;
+    
+    println!("{} Exported {} collection(s), {} record(s) to {}",
+        "✓".green(), archive.collections.len(), archive.item_count(), path.bold());
+    Ok(())
+}
+
+pub async fn import(ctx: &AppContext, input: String, mode: String, dry_run: bool) -> Result<()> {
+    let mode: ImportMode = mode.parse()?;
+    let contents = std::fs::read_to_string(&input)
+        .with_context(|| format!("failed to read {}", input))?;
+    let archive: Archive = serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not a timeless export", input))?;
+    
+    println!("{} Importing {} (exported {}, schema v{}){}",
+        "✓".green(), input.bold(), archive.exported_at.format("%Y-%m-%d %H:%M"), archive.schema_version,
+        if dry_run { " - dry run" } else { "" });
+    
+    let reports = archive::import(ctx.repo.store(), archive, mode, dry_run)?;
+    for report in &reports {
+        match mode {
+            ImportMode::Replace => {
+                println!("  {} {}: replaced with {} record(s)", "✓".green(), report.collection, report.replaced);
+            }
+            ImportMode::Merge => {
+                println!("  {} {}: {} added, {} updated, {} unchanged, {} conflict(s)",
+                    "✓".green(), report.collection, report.added, report.updated, report.unchanged, report.conflicts.len());
+            }
+        }
+        
+        for conflict in &report.conflicts {
+            let format_time = |t: Option<chrono::DateTime<chrono::Utc>>| {
+                t.map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap_or_else(|| "unknown".to_string())
+            };
+            let kept = match conflict.resolution {
+                Resolution::KeptExisting => "kept existing",
+                Resolution::TookIncoming => "took incoming",
+            };
+            println!("      {} {} (existing {}, incoming {}): {}",
+                "!".yellow(), conflict.id, format_time(conflict.existing_updated_at),
+                format_time(conflict.incoming_updated_at), kept);
+        }
+    }
+    
+    if dry_run {
+        println!("{} Dry run complete, nothing was written", "✓".green());
+    } else {
+        println!("{} Import complete!", "✓".green());
+    }
+    Ok(())
+}
+
+pub async fn query_jira(_ctx: &AppContext, projects: Vec<String>, period: String, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    eprintln!("{} Querying Jira for work items...", "✓".green());
     
     let jira = JiraService::new();
     
-    match period.as_str() {
+    let response = match period.as_str() {
         "week" | "weekly" => {
-            let response = jira.get_work_items_for_week(&projects).await?;
-            println!("{} Weekly Jira Report:", "📊".blue());
-            println!("{}", response);
+            eprintln!("{} Weekly Jira Report:", "📊".blue());
+            jira.get_work_items_for_week(&projects).await?
         }
         _ => {
             let jql = format!("project in ({}) AND updated >= -{}", projects.join(","), period);
-            let response = jira.search_tickets(&jql).await?;
-            println!("{} Jira Search Results:", "🔍".blue());
-            println!("{}", response);
+            eprintln!("{} Jira Search Results:", "🔍".blue());
+            jira.search_tickets(&jql).await?
         }
+    };
+    
+    match response {
+        Structured::Parsed(tickets) => print_tickets(&tickets, format)?,
+        Structured::Raw(text) => {
+            eprintln!("{} Claude did not return structured tickets; showing its reply as is", "!".yellow());
+            println!("{}", text);
+        }
+    }
+    
+    Ok(())
+}
+
+fn print_tickets(tickets: &[JiraTicket], format: OutputFormat) -> Result<()> {
+    if format == OutputFormat::Json {
+        return output::print_json(&tickets);
+    }
+    if tickets.is_empty() {
+        println!("No tickets found");
+        return Ok(());
     }
     
+    let rows: Vec<Vec<String>> = tickets.iter().map(|t| vec![
+        t.key.clone(),
+        t.status.clone(),
+        t.priority.clone().unwrap_or_else(|| "-".to_string()),
+        t.assignee.clone().unwrap_or_else(|| "unassigned".to_string()),
+        t.updated.map(|u| u.format("%Y-%m-%d").to_string()).unwrap_or_else(|| "-".to_string()),
+        t.summary.clone(),
+    ]).collect();
+    output::print_table(&["KEY", "STATUS", "PRIORITY", "ASSIGNEE", "UPDATED", "SUMMARY"], &rows);
+    
+    let mut by_status: BTreeMap<&str, usize> = BTreeMap::new();
+    for ticket in tickets {
+        *by_status.entry(ticket.status.as_str()).or_default() += 1;
+    }
+    let counts: Vec<String> = by_status.iter().map(|(status, n)| format!("{} {}", n, status)).collect();
+    println!("\n{} tickets: {}", tickets.len(), counts.join(", "));
     Ok(())
 }
 
-pub async fn send_slack_message(channel: String, message: String) -> Result<()> {
+pub async fn send_slack_message(_ctx: &AppContext, channel: String, message: String) -> Result<()> {
     println!("{} Sending message to Slack...", "✓".green());
     
     let slack = SlackService::new();
@@ -138,11 +250,24 @@ pub async fn send_slack_message(channel: String, message: String) -> Result<()>
     Ok(())
 }
 
-pub async fn collect_team_status(channel: String, members: Vec<String>) -> Result<()> {
+pub async fn collect_team_status(ctx: &AppContext, channel: String, members: Vec<String>) -> Result<()> {
+    // Resolve every argument up front so a typo fails before anything is sent
+    let members = members
+        .iter()
+        .map(|query| ctx.repo.resolve_member(query))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mentions: Vec<String> = members
+        .iter()
+        .map(|m| match &m.slack_id {
+            Some(slack_id) => format!("{} (Slack {})", m.name, slack_id),
+            None => m.name.clone(),
+        })
+        .collect();
+    
     println!("{} Collecting team status...", "✓".green());
     
     let slack = SlackService::new();
-    let response = slack.collect_team_status(&channel, &members).await?;
+    let response = slack.collect_team_status(&channel, &mentions).await?;
     
     println!("{} Team Status Summary:", "👥".blue());
     println!("{}", response);
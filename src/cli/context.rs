@@ -0,0 +1,28 @@
+use anyhow::{Context, Result};
+use std::sync::Arc;
+use crate::claude::{ClaudeCliManager, ClaudeCliProcess};
+use crate::config::Config;
+use crate::storage::{StorageBackend, TeamRepository};
+
+/// Shared state built once at startup and handed to every command.
+pub struct AppContext {
+    pub config: Config,
+    pub repo: TeamRepository<StorageBackend>,
+    pub claude: Arc<ClaudeCliProcess>,
+}
+
+impl AppContext {
+    pub async fn new(config: Config) -> Result<Self> {
+        let data_dir = config.app.data_path();
+        let store = StorageBackend::from_config(&config.storage, &data_dir)
+            .with_context(|| format!("failed to open data directory {}", data_dir.display()))?;
+        let repo = TeamRepository::with_store(store);
+        let claude = ClaudeCliManager::get_instance().await?;
+
+        Ok(AppContext {
+            config,
+            repo,
+            claude,
+        })
+    }
+}
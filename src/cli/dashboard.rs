@@ -0,0 +1,107 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use ratatui::DefaultTerminal;
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+use crate::cli::AppContext;
+use crate::dashboard::ui::{self, DashboardState};
+use crate::dashboard::DashboardData;
+
+/// How long to wait for a key press before checking for file changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+pub async fn run(ctx: &AppContext) -> Result<()> {
+    // Load once before touching the terminal so startup errors print normally
+    let data = load(ctx)?;
+
+    let data_path = ctx.config.app.data_path();
+    let (tx, changes) = mpsc::channel();
+    let mut state = DashboardState::default();
+    let watcher = match watch(&data_path, tx) {
+        Ok(watcher) => {
+            state.status = format!("watching {}", data_path.display());
+            Some(watcher)
+        }
+        Err(err) => {
+            state.status = format!("not watching {} ({}); press r to refresh", data_path.display(), err);
+            None
+        }
+    };
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(ctx, &mut terminal, data, &mut state, &changes);
+    ratatui::restore();
+    drop(watcher);
+    result
+}
+
+fn load(ctx: &AppContext) -> Result<DashboardData> {
+    DashboardData::load(&ctx.repo, &ctx.config.team.name, &ctx.config.metrics, Utc::now())
+}
+
+fn watch(path: &std::path::Path, tx: mpsc::Sender<()>) -> notify::Result<RecommendedWatcher> {
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        if event.is_ok_and(|e| is_data_change(&e)) {
+            let _ = tx.send(());
+        }
+    })?;
+    watcher.watch(path, RecursiveMode::Recursive)?;
+    Ok(watcher)
+}
+
+/// Whether an event changed stored data. Reads show up as access events on
+/// some platforms, and lock files and SQLite's shared-memory index change
+/// while the dashboard itself is reading.
+fn is_data_change(event: &notify::Event) -> bool {
+    let bookkeeping = |path: &std::path::PathBuf| {
+        let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        name.ends_with(".lock") || name.ends_with("-shm")
+    };
+    !event.kind.is_access() && !event.paths.iter().all(bookkeeping)
+}
+
+fn event_loop(
+    ctx: &AppContext,
+    terminal: &mut DefaultTerminal,
+    mut data: DashboardData,
+    state: &mut DashboardState,
+    changes: &Receiver<()>,
+) -> Result<()> {
+    let watching = state.status.clone();
+    loop {
+        terminal.draw(|frame| ui::draw(frame, &data, state)).context("failed to draw the dashboard")?;
+
+        let mut refresh = false;
+        if event::poll(POLL_INTERVAL)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    match key.code {
+                        KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+                        KeyCode::Char('r') => refresh = true,
+                        KeyCode::Down | KeyCode::Char('j') => state.select_next(data.roster.len()),
+                        KeyCode::Up | KeyCode::Char('k') => state.select_previous(),
+                        _ => {}
+                    }
+                }
+            }
+        }
+        // A save touches several files; one reload covers the whole burst
+        while changes.try_recv().is_ok() {
+            refresh = true;
+        }
+
+        if refresh {
+            match load(ctx) {
+                Ok(fresh) => {
+                    data = fresh;
+                    state.status = watching.clone();
+                }
+                // Keep showing the last good data, e.g. while a file is mid-write
+                Err(err) => state.status = format!("refresh failed: {:#}", err),
+            }
+        }
+    }
+}
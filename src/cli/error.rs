@@ -0,0 +1,124 @@
+use colored::Colorize;
+use crate::config::ConfigError;
+use crate::models::{MemberError, ProjectError};
+use crate::storage::resolver::ResolveError;
+use crate::storage::{RepositoryError, StorageError};
+
+// Process exit codes. 2 is left to clap for usage errors.
+pub const EXIT_FAILURE: i32 = 1;
+pub const EXIT_NOT_FOUND: i32 = 3;
+pub const EXIT_AMBIGUOUS: i32 = 4;
+pub const EXIT_INVALID: i32 = 5;
+pub const EXIT_CONFLICT: i32 = 6;
+pub const EXIT_LOCKED: i32 = 7;
+pub const EXIT_CORRUPT: i32 = 8;
+pub const EXIT_SCHEMA: i32 = 9;
+pub const EXIT_STORAGE: i32 = 10;
+
+/// The exit code for the most specific typed error in `err`'s chain.
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    err.chain().find_map(classify).map_or(EXIT_FAILURE, |(code, _)| code)
+}
+
+/// Prints `err` with its causes and, where one applies, a hint on what to do.
+pub fn report(err: &anyhow::Error) {
+    eprintln!("{} {}", "Error:".red().bold(), err);
+    let mut previous = err.to_string();
+    for cause in err.chain().skip(1) {
+        // Some errors already include their source in the message
+        let message = cause.to_string();
+        if !previous.contains(&message) {
+            eprintln!("  {} {}", "caused by:".dimmed(), message);
+        }
+        previous = message;
+    }
+    if let Some(hint) = err.chain().find_map(classify).and_then(|(_, hint)| hint) {
+        eprintln!("  {} {}", "hint:".yellow(), hint);
+    }
+}
+
+fn classify(err: &(dyn std::error::Error + 'static)) -> Option<(i32, Option<&'static str>)> {
+    if let Some(err) = err.downcast_ref::<RepositoryError>() {
+        return match err {
+            RepositoryError::Storage(err) => Some(classify_storage(err)),
+            RepositoryError::Member(err) => Some(classify_member(err)),
+            RepositoryError::InvalidAssignment(_) => Some((EXIT_INVALID, None)),
+            RepositoryError::Resolve(err) => Some(classify_resolve(err)),
+            RepositoryError::NotFound { kind, .. } => Some((EXIT_NOT_FOUND, list_hint(kind))),
+        };
+    }
+    if let Some(err) = err.downcast_ref::<StorageError>() {
+        return Some(classify_storage(err));
+    }
+    if let Some(err) = err.downcast_ref::<MemberError>() {
+        return Some(classify_member(err));
+    }
+    if let Some(err) = err.downcast_ref::<ResolveError>() {
+        return Some(classify_resolve(err));
+    }
+    if let Some(err) = err.downcast_ref::<ProjectError>() {
+        return Some(classify_project(err));
+    }
+    if err.downcast_ref::<ConfigError>().is_some() {
+        return Some((EXIT_INVALID, Some("fix the listed settings in the config file or environment")));
+    }
+    None
+}
+
+fn classify_storage(err: &StorageError) -> (i32, Option<&'static str>) {
+    match err {
+        StorageError::Missing(_) => (EXIT_NOT_FOUND, Some("check --data-dir or app.data_dir in the config")),
+        StorageError::LockTimeout { .. } => {
+            (EXIT_LOCKED, Some("wait for the other process to finish, or raise storage.lock_timeout_ms"))
+        }
+        StorageError::Database(rusqlite::Error::SqliteFailure(failure, _))
+            if matches!(failure.code, rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked) =>
+        {
+            (EXIT_LOCKED, Some("wait for the other process to finish, or raise storage.lock_timeout_ms"))
+        }
+        StorageError::Corrupt { .. } | StorageError::Serialization(_) => {
+            (EXIT_CORRUPT, Some("restore the file from its .bak copy or re-import an export"))
+        }
+        StorageError::UnsupportedSchema { .. } => (EXIT_SCHEMA, Some("install a newer timeless to read this data")),
+        StorageError::Migration { .. } => (EXIT_SCHEMA, Some("run `timeless migrate --dry-run` to inspect the data")),
+        StorageError::InvalidMember { source, .. } => classify_member(source),
+        StorageError::UnsupportedFormat(_) => (EXIT_INVALID, Some("set storage.data_format to json or sqlite")),
+        StorageError::Io { .. }
+        | StorageError::InvalidKey(_)
+        | StorageError::Database(_)
+        | StorageError::Poisoned(_) => (EXIT_STORAGE, None),
+    }
+}
+
+fn classify_member(err: &MemberError) -> (i32, Option<&'static str>) {
+    match err {
+        MemberError::DuplicateEmail { .. } | MemberError::DuplicateSlackId { .. } => {
+            (EXIT_CONFLICT, Some("use `timeless member edit` to change the existing member"))
+        }
+        MemberError::EmptyName | MemberError::InvalidEmail(_) | MemberError::InvalidSlackId(_) => (EXIT_INVALID, None),
+    }
+}
+
+fn classify_project(err: &ProjectError) -> (i32, Option<&'static str>) {
+    match err {
+        ProjectError::AlreadyInStatus { .. } => (EXIT_INVALID, None),
+        ProjectError::TransitionNotAllowed { .. } => {
+            (EXIT_INVALID, Some("pass --force to reopen a completed or cancelled project"))
+        }
+    }
+}
+
+fn list_hint(kind: &str) -> Option<&'static str> {
+    match kind {
+        "project" => Some("`timeless project list` shows every project"),
+        "blocker" => Some("`timeless blockers list` shows every blocker"),
+        _ => None,
+    }
+}
+
+fn classify_resolve(err: &ResolveError) -> (i32, Option<&'static str>) {
+    match err {
+        ResolveError::NotFound(_) => (EXIT_NOT_FOUND, Some("`timeless member list` shows every member")),
+        ResolveError::Ambiguous { .. } => (EXIT_AMBIGUOUS, None),
+    }
+}
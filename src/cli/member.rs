@@ -0,0 +1,140 @@
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::io::{self, IsTerminal};
+use crate::cli::AppContext;
+use crate::cli::output::{self, OutputFormat};
+use crate::cli::project::date_range;
+use crate::cli::status::Prompter;
+use crate::models::TeamMember;
+use crate::storage::resolver::ResolveError;
+use crate::storage::RepositoryError;
+
+/// Looks a member up by id, id prefix, email, Slack ID or name.
+pub fn find_member(ctx: &AppContext, query: &str) -> Result<TeamMember> {
+    Ok(ctx.repo.resolve_member(query)?)
+}
+
+fn member_row(member: &TeamMember) -> Vec<String> {
+    vec![
+        member.id.to_string(),
+        member.name.clone(),
+        member.email.clone(),
+        member.role.clone(),
+        member.slack_id.clone().unwrap_or_else(|| "-".to_string()),
+    ]
+}
+
+pub async fn list(ctx: &AppContext, role: Option<String>, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let mut members = ctx.repo.list_team_members()?;
+    if let Some(role) = &role {
+        members.retain(|m| m.role.eq_ignore_ascii_case(role));
+    }
+    members.sort_by_key(|m| m.name.to_lowercase());
+    
+    match format {
+        OutputFormat::Json => output::print_json(&members)?,
+        OutputFormat::Table => {
+            if members.is_empty() {
+                println!("No team members found");
+            } else {
+                let rows: Vec<Vec<String>> = members.iter().map(member_row).collect();
+                output::print_table(&["ID", "NAME", "EMAIL", "ROLE", "SLACK"], &rows);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn show(ctx: &AppContext, member: String, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let member = find_member(ctx, &member)?;
+    
+    match format {
+        OutputFormat::Json => output::print_json(&member)?,
+        OutputFormat::Table => {
+            println!("{}", member.name.bold());
+            println!("  ID:       {}", member.id);
+            println!("  Email:    {}", member.email);
+            println!("  Role:     {}", member.role);
+            println!("  Slack ID: {}", member.slack_id.as_deref().unwrap_or("-"));
+            println!("  Created:  {}", member.created_at.format("%Y-%m-%d %H:%M"));
+            println!("  Updated:  {}", member.updated_at.format("%Y-%m-%d %H:%M"));
+            
+            let projects = ctx.repo.get_projects_for_member(member.id, Utc::now().date_naive())?;
+            if !projects.is_empty() {
+                println!("\n  {}", "Projects".bold());
+                for (project, assignment) in &projects {
+                    println!("  {:>3}%  {}{}", assignment.allocation, project.name, date_range(assignment));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn edit(
+    ctx: &AppContext,
+    member: String,
+    name: Option<String>,
+    email: Option<String>,
+    role: Option<String>,
+    slack_id: Option<String>,
+) -> Result<()> {
+    let mut member = find_member(ctx, &member)?;
+    
+    if name.is_none() && email.is_none() && role.is_none() && slack_id.is_none() {
+        println!("{} Nothing to change for {}", "!".yellow(), member.name.bold());
+        return Ok(());
+    }
+    
+    if let Some(name) = name {
+        member.name = name.trim().to_string();
+    }
+    if let Some(email) = email {
+        member.email = email.trim().to_string();
+    }
+    if let Some(role) = role {
+        member.role = role;
+    }
+    if let Some(slack_id) = slack_id {
+        // An empty value clears the Slack ID
+        member.slack_id = Some(slack_id.trim().to_string()).filter(|id| !id.is_empty());
+    }
+    member.update();
+    
+    ctx.repo.save_team_member(&member)
+        .with_context(|| format!("could not update {}", member.name))?;
+    println!("{} Updated {} ({})", "✓".green(), member.name.bold(), member.id);
+    Ok(())
+}
+
+/// Removes a member named by id, id prefix or email. Any other reference is
+/// resolved like elsewhere but has to be confirmed first.
+pub async fn remove(ctx: &AppContext, member: String) -> Result<()> {
+    let member = match ctx.repo.resolve_member_exact(&member) {
+        Ok(found) => found,
+        Err(RepositoryError::Resolve(ResolveError::NotFound(_))) => {
+            let found = find_member(ctx, &member)?;
+            if !io::stdin().is_terminal() {
+                bail!(
+                    "{:?} matched {} <{}> by name; pass their email or id to remove them",
+                    member, found.name, found.email
+                );
+            }
+            let mut prompter = Prompter::new(io::stdin().lock(), io::stdout());
+            let question = format!("Remove {} <{}> and their assignments? [y/N]", found.name, found.email);
+            if !prompter.ask(&question)?.is_some_and(|answer| answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes")) {
+                println!("{} Nothing removed", "!".yellow());
+                return Ok(());
+            }
+            found
+        }
+        Err(err) => return Err(err.into()),
+    };
+    ctx.repo.remove_team_member(member.id)?;
+    
+    println!("{} Removed {} ({})", "✓".green(), member.name.bold(), member.id);
+    Ok(())
+}
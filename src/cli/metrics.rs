@@ -0,0 +1,129 @@
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use colored::Colorize;
+use crate::cli::AppContext;
+use crate::cli::output::{self, OutputFormat};
+use crate::config::MetricsConfig;
+use crate::metrics::{self, trends};
+use crate::models::{MetricsPeriod, TeamMetrics};
+
+/// Parses a positive age such as `3d`, `12h` or `2w`.
+pub fn parse_age(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let invalid = || anyhow!("invalid age {:?}, expected a positive number followed by h, d or w (e.g. 3d)", value);
+    let unit = value.chars().last().ok_or_else(invalid)?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().map_err(|_| invalid())?;
+    if amount <= 0 {
+        return Err(invalid());
+    }
+    let age = match unit {
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    };
+    age.ok_or_else(invalid)
+}
+
+/// Parses a `--since` value: a `YYYY-MM-DD` date (midnight UTC) or an age
+/// such as `14d` counted back from `now`.
+pub fn parse_since(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+    let context = || format!("invalid --since {:?}, expected YYYY-MM-DD or an age like 14d", value);
+    let age = parse_age(value).with_context(context)?;
+    now.checked_sub_signed(age).with_context(context)
+}
+
+pub fn metrics_row(metrics: &TeamMetrics, config: &MetricsConfig) -> Vec<String> {
+    let concern = if metrics.concerns(config).is_empty() { "" } else { "!" };
+    vec![
+        metrics.date.format("%Y-%m-%d").to_string(),
+        metrics.period.map_or_else(|| "manual".to_string(), |p| p.to_string()),
+        metrics.active_members.to_string(),
+        metrics.blockers_count.to_string(),
+        metrics.completed_tasks.to_string(),
+        metrics.average_satisfaction.map_or_else(|| "-".to_string(), |s| format!("{:.1}", s)),
+        format!("{:.1}{}", metrics.health_score_with(config), concern),
+    ]
+}
+
+pub async fn compute(
+    ctx: &AppContext,
+    since: Option<String>,
+    period: String,
+    dry_run: bool,
+    format: String,
+) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let period: MetricsPeriod = period.parse()?;
+    let since = match since {
+        Some(since) => parse_since(&since, Utc::now())?,
+        None => Utc::now() - Duration::days(7),
+    };
+
+    let snapshots = metrics::compute(&ctx.repo, since, period, dry_run)?;
+
+    match format {
+        OutputFormat::Json => output::print_json(&snapshots)?,
+        OutputFormat::Table => {
+            if snapshots.is_empty() {
+                println!("No status updates since {}", since.format("%Y-%m-%d"));
+                return Ok(());
+            }
+            let rows: Vec<Vec<String>> = snapshots.iter().map(|m| metrics_row(m, &ctx.config.metrics)).collect();
+            output::print_table(&["DATE", "PERIOD", "ACTIVE", "BLOCKERS", "ACHIEVEMENTS", "SATISFACTION", "HEALTH"], &rows);
+            if dry_run {
+                println!("\n{} Dry run: {} snapshot(s) not saved", "!".yellow(), snapshots.len());
+            } else {
+                println!("\n{} Saved {} {} snapshot(s)", "✓".green(), snapshots.len(), period);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn trends(ctx: &AppContext, weeks: usize, window: usize, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    if weeks == 0 {
+        bail!("--weeks must be at least 1");
+    }
+
+    let today = Utc::now().date_naive();
+    let first_week = MetricsPeriod::Weekly.period_start(today) - Duration::weeks(weeks as i64 - 1);
+    let start = first_week.and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let snapshots = ctx.repo.get_team_metrics_range(start, Utc::now())?;
+    let trends = trends::analyze(&snapshots, today, weeks, window);
+
+    match format {
+        OutputFormat::Json => output::print_json(&trends)?,
+        OutputFormat::Table => {
+            if snapshots.is_empty() {
+                println!("No metrics since {}; run `timeless metrics compute` first", first_week);
+                return Ok(());
+            }
+            println!("Weekly trends from {} ({} weeks, {}-week moving average)\n", first_week, weeks, window);
+            let number = |value: Option<f32>| value.map_or_else(|| "-".to_string(), |v| format!("{:.2}", v));
+            let rows: Vec<Vec<String>> = trends.series.iter().map(|series| {
+                let flags: Vec<String> = series.anomalies.iter()
+                    .map(|&i| trends.weeks[i].week_start.format("%m-%d").to_string())
+                    .collect();
+                vec![
+                    series.series.to_string(),
+                    series.sparkline(),
+                    number(series.latest()),
+                    number(series.moving_average.last().copied().flatten()),
+                    series.week_over_week.last().copied().flatten().map_or_else(|| "-".to_string(), |d| format!("{:+.2}", d)),
+                    if flags.is_empty() { String::new() } else { format!("unusual: {}", flags.join(", ")) },
+                ]
+            }).collect();
+            output::print_table(&["METRIC", "TREND", "LATEST", "AVERAGE", "WOW", "FLAGS"], &rows);
+
+            if trends.series.iter().any(|s| s.anomalies.last() == Some(&(weeks - 1))) {
+                println!("\n{} This week moved unusually far compared with the weeks before it", "!".yellow());
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,14 @@
+pub mod blockers;
 pub mod commands;
+pub mod context;
+pub mod dashboard;
+pub mod error;
+pub mod member;
+pub mod metrics;
+pub mod output;
+pub mod project;
+pub mod report;
+pub mod status;
 
-pub use commands::*;
\ No newline at end of file
+pub use commands::*;
+pub use context::AppContext;
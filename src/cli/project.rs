@@ -0,0 +1,242 @@
+use anyhow::{bail, Context, Result};
+use chrono::{NaiveDate, Utc};
+use colored::Colorize;
+use uuid::Uuid;
+use crate::cli::AppContext;
+use crate::cli::member::find_member;
+use crate::cli::output::{self, OutputFormat};
+use crate::models::{Assignment, Project, ProjectStatus};
+use crate::storage::RepositoryError;
+
+/// Looks a project up by id or (case-insensitive) name.
+pub fn find_project(ctx: &AppContext, query: &str) -> Result<Project> {
+    if let Ok(id) = Uuid::parse_str(query) {
+        if let Some(project) = ctx.repo.get_project(id)? {
+            return Ok(project);
+        }
+    }
+
+    let project = ctx.repo.list_projects()?
+        .into_iter()
+        .find(|p| p.name.eq_ignore_ascii_case(query));
+    Ok(project.ok_or_else(|| RepositoryError::NotFound { kind: "project", query: query.to_string() })?)
+}
+
+fn project_row(project: &Project) -> Vec<String> {
+    vec![
+        project.id.to_string(),
+        project.name.clone(),
+        project.status.to_string(),
+        if project.is_archived() { "yes" } else { "no" }.to_string(),
+        project.updated_at.format("%Y-%m-%d").to_string(),
+    ]
+}
+
+pub async fn create(ctx: &AppContext, name: String, description: String) -> Result<()> {
+    if name.trim().is_empty() {
+        bail!("project name must not be empty");
+    }
+    if ctx.repo.list_projects()?.iter().any(|p| p.name.eq_ignore_ascii_case(&name)) {
+        bail!("a project named {:?} already exists", name);
+    }
+
+    let project = Project::new(name, description);
+    ctx.repo.save_project(&project)?;
+    println!("{} Created project {} ({})", "✓".green(), project.name.bold(), project.id);
+    Ok(())
+}
+
+pub async fn list(ctx: &AppContext, status: Option<String>, all: bool, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let status = status.map(|s| s.parse::<ProjectStatus>()).transpose()?;
+
+    let mut projects = ctx.repo.list_projects()?;
+    projects.retain(|p| all || !p.is_archived());
+    if let Some(status) = status {
+        projects.retain(|p| p.status == status);
+    }
+    projects.sort_by_key(|p| p.name.to_lowercase());
+
+    match format {
+        OutputFormat::Json => output::print_json(&projects)?,
+        OutputFormat::Table => {
+            if projects.is_empty() {
+                println!("No projects found");
+            } else {
+                let rows: Vec<Vec<String>> = projects.iter().map(project_row).collect();
+                output::print_table(&["ID", "NAME", "STATUS", "ARCHIVED", "UPDATED"], &rows);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn show(ctx: &AppContext, project: String, format: String) -> Result<()> {
+    let format: OutputFormat = format.parse()?;
+    let project = find_project(ctx, &project)?;
+
+    match format {
+        OutputFormat::Json => output::print_json(&project)?,
+        OutputFormat::Table => {
+            println!("{}", project.name.bold());
+            println!("  ID:          {}", project.id);
+            println!("  Description: {}", project.description);
+            println!("  Status:      {}", project.status);
+            if let Some(archived_at) = project.archived_at {
+                println!("  Archived:    {}", archived_at.format("%Y-%m-%d %H:%M"));
+            }
+            println!("  Created:     {}", project.created_at.format("%Y-%m-%d %H:%M"));
+            println!("  Updated:     {}", project.updated_at.format("%Y-%m-%d %H:%M"));
+
+            let members = ctx.repo.get_members_on_project(project.id, Utc::now().date_naive())?;
+            if !members.is_empty() {
+                println!("\n  {}", "Members".bold());
+                for (member, assignment) in &members {
+                    println!("  {:>3}%  {}{}", assignment.allocation, member.name, date_range(assignment));
+                }
+            }
+
+            if !project.status_history.is_empty() {
+                println!("\n  {}", "Status history".bold());
+                for change in &project.status_history {
+                    let forced = if change.forced { " (forced)".yellow().to_string() } else { String::new() };
+                    let note = change.note.as_deref().map(|n| format!(" - {}", n)).unwrap_or_default();
+                    println!(
+                        "  {}  {} -> {}{}{}",
+                        change.changed_at.format("%Y-%m-%d %H:%M"),
+                        change.from, change.to, forced, note
+                    );
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn set_status(
+    ctx: &AppContext,
+    project: String,
+    status: String,
+    force: bool,
+    note: Option<String>,
+) -> Result<()> {
+    let status: ProjectStatus = status.parse()?;
+    let mut project = find_project(ctx, &project)?;
+    if project.is_archived() {
+        bail!("project {} is archived", project.name);
+    }
+
+    let previous = project.status;
+    project.transition_to(status, force, note)?;
+    ctx.repo.save_project(&project)?;
+
+    println!(
+        "{} {} is now {} (was {})",
+        "✓".green(), project.name.bold(), project.status, previous
+    );
+    Ok(())
+}
+
+pub async fn archive(ctx: &AppContext, project: String, force: bool) -> Result<()> {
+    let mut project = find_project(ctx, &project)?;
+    if project.is_archived() {
+        println!("{} {} is already archived", "!".yellow(), project.name.bold());
+        return Ok(());
+    }
+    if !force && !project.status.is_closed() {
+        bail!(
+            "project {} is still {}; complete or cancel it first, or pass --force",
+            project.name, project.status
+        );
+    }
+
+    project.archive();
+    ctx.repo.save_project(&project)?;
+    println!("{} Archived {} ({})", "✓".green(), project.name.bold(), project.id);
+    Ok(())
+}
+
+fn parse_date(value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .with_context(|| format!("invalid date {:?}, expected YYYY-MM-DD", value))
+}
+
+/// Renders an assignment's dates as a suffix for list output.
+pub fn date_range(assignment: &Assignment) -> String {
+    match assignment.end_date {
+        Some(end) => format!(" ({} to {})", assignment.start_date, end),
+        None => format!(" (since {})", assignment.start_date),
+    }
+}
+
+pub async fn assign(
+    ctx: &AppContext,
+    member: String,
+    project: String,
+    allocation: Option<u8>,
+    start: Option<String>,
+    end: Option<String>,
+) -> Result<()> {
+    let member = find_member(ctx, &member)?;
+    let project = find_project(ctx, &project)?;
+    if project.is_archived() || project.status.is_closed() {
+        bail!("project {} is {}; reopen it before assigning members", project.name, project.status);
+    }
+
+    let today = Utc::now().date_naive();
+    let start_date = start.as_deref().map(parse_date).transpose()?;
+    let end_date = end.as_deref().map(parse_date).transpose()?;
+
+    // Re-assigning updates the member's current assignment instead of adding
+    // a second one. Allocation and start are kept unless passed; the end date
+    // is always replaced, so leaving out --end makes it open-ended again
+    let existing = ctx.repo.get_assignments_for_member(member.id)?
+        .into_iter()
+        .find(|a| a.project_id == project.id && a.end_date.is_none_or(|end| end >= start_date.unwrap_or(today)));
+    let assignment = match existing {
+        Some(mut assignment) => {
+            if let Some(allocation) = allocation {
+                assignment.allocation = allocation;
+            }
+            if let Some(start_date) = start_date {
+                assignment.start_date = start_date;
+            }
+            assignment.end_date = end_date;
+            assignment.updated_at = Utc::now();
+            assignment
+        }
+        None => {
+            let mut assignment = Assignment::new(member.id, project.id, allocation.unwrap_or(100), start_date.unwrap_or(today));
+            assignment.end_date = end_date;
+            assignment
+        }
+    };
+    ctx.repo.save_assignment(&assignment)?;
+
+    println!(
+        "{} Assigned {} to {} at {}%{}",
+        "✓".green(), member.name.bold(), project.name.bold(), assignment.allocation, date_range(&assignment)
+    );
+
+    let total: u32 = ctx.repo.get_projects_for_member(member.id, assignment.start_date)?
+        .iter()
+        .map(|(_, a)| u32::from(a.allocation))
+        .sum();
+    if total > 100 {
+        println!("{} {} is now allocated {}% across projects", "!".yellow(), member.name, total);
+    }
+    Ok(())
+}
+
+pub async fn unassign(ctx: &AppContext, member: String, project: String) -> Result<()> {
+    let member = find_member(ctx, &member)?;
+    let project = find_project(ctx, &project)?;
+
+    let today = Utc::now().date_naive();
+    if ctx.repo.end_assignments(member.id, project.id, today)? == 0 {
+        bail!("{} is not currently assigned to {}", member.name, project.name);
+    }
+
+    println!("{} Unassigned {} from {} as of {}", "✓".green(), member.name.bold(), project.name.bold(), today);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anyhow::{Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use std::path::PathBuf;
+use crate::cli::AppContext;
+use crate::reports::{Report, ReportFormat, ReportType};
+
+pub async fn generate(
+    ctx: &AppContext,
+    report_type: String,
+    output: Option<String>,
+    format: Option<String>,
+    ai_summary: bool,
+) -> Result<()> {
+    let report_type: ReportType = report_type.parse()?;
+    let format: ReportFormat = match format {
+        Some(format) => format.parse()?,
+        None => ReportFormat::Markdown,
+    };
+
+    let mut report = Report::gather(&ctx.repo, report_type, &ctx.config.team.name, &ctx.config.metrics, Utc::now())?;
+    eprintln!("{} Generating {} report for {}", "✓".green(), report_type.to_string().bold(), report.time_period());
+
+    // The summary is an extra; the report is still written without it
+    if ai_summary {
+        if !ctx.config.claude.enabled {
+            eprintln!("{} Claude CLI integration is disabled; skipping the summary", "!".yellow());
+        } else {
+            match ctx.claude.send_prompt(&report.prompt()).await {
+                Ok(summary) => report.summary = Some(summary),
+                Err(err) => eprintln!("{} Could not get a summary from Claude CLI: {}", "!".yellow(), err),
+            }
+        }
+    }
+
+    let text = report.render(format)?;
+    match output {
+        Some(path) => {
+            // `-o weekly` writes weekly.md (or .html/.json for other formats)
+            let mut path = PathBuf::from(path);
+            if path.extension().is_none() {
+                path.set_extension(format.extension());
+            }
+            std::fs::write(&path, &text).with_context(|| format!("failed to write report to {}", path.display()))?;
+            eprintln!("{} Report written to {}", "✓".green(), path.display().to_string().bold());
+        }
+        None => println!("{}", text),
+    }
+    Ok(())
+}
@@ -0,0 +1,390 @@
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use uuid::Uuid;
+use crate::cli::AppContext;
+use crate::cli::member::find_member;
+use crate::dashboard::format_age;
+use crate::models::{Blocker, StatusUpdate, TeamMember};
+use crate::services::SlackService;
+
+/// Line-based questions over any reader and writer, so the interactive flow
+/// works the same against a terminal or a scripted input.
+pub struct Prompter<R, W> {
+    input: R,
+    output: W,
+    closed: bool,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Prompter { input, output, closed: false }
+    }
+
+    /// Whether the input has reached end of file.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    pub fn say(&mut self, line: &str) -> Result<()> {
+        writeln!(self.output, "{}", line)?;
+        Ok(())
+    }
+
+    /// Asks a question and returns the trimmed answer, or `None` if the
+    /// answer was empty or the input is closed.
+    pub fn ask(&mut self, question: &str) -> Result<Option<String>> {
+        if self.closed {
+            return Ok(None);
+        }
+        write!(self.output, "{} ", question)?;
+        self.output.flush()?;
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line)? == 0 {
+            self.closed = true;
+            writeln!(self.output)?;
+            return Ok(None);
+        }
+        let answer = line.trim();
+        Ok((!answer.is_empty()).then(|| answer.to_string()))
+    }
+
+    /// Asks for entries one per line until an empty answer.
+    pub fn ask_list(&mut self, question: &str) -> Result<Vec<String>> {
+        self.say(&format!("{} (one per line, empty line to finish)", question))?;
+        let mut entries = Vec::new();
+        while let Some(entry) = self.ask("  -")? {
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+/// The member's most recent status update, if any.
+pub fn last_update_for(ctx: &AppContext, member: &TeamMember) -> Result<Option<StatusUpdate>> {
+    Ok(ctx.repo.get_status_updates_for_member(member.id)?
+        .into_iter()
+        .max_by_key(|update| update.timestamp))
+}
+
+fn describe_update(update: &StatusUpdate) -> Vec<String> {
+    let mut lines = vec![format!(
+        "Last update ({}): {}",
+        update.timestamp.format("%Y-%m-%d %H:%M"),
+        update.content
+    )];
+    if let Some(mood) = &update.mood {
+        lines.push(format!("  Mood: {}", mood));
+    }
+    for blocker in &update.blockers {
+        lines.push(format!("  Blocker: {}", blocker));
+    }
+    for achievement in &update.achievements {
+        lines.push(format!("  Achievement: {}", achievement));
+    }
+    lines
+}
+
+/// Walks one member through the status questions. Returns `None` when the
+/// member is skipped by leaving the content empty.
+pub fn prompt_status_update<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    member: &TeamMember,
+    last: Option<&StatusUpdate>,
+) -> Result<Option<StatusUpdate>> {
+    prompter.say(&format!("\n{} ({})", member.name.bold(), member.role))?;
+    match last {
+        Some(update) => {
+            for line in describe_update(update) {
+                prompter.say(&line.dimmed().to_string())?;
+            }
+        }
+        None => prompter.say(&"No previous updates".dimmed().to_string())?,
+    }
+
+    let Some(content) = prompter.ask("What are you working on? (empty to skip)")? else {
+        return Ok(None);
+    };
+
+    let mut update = StatusUpdate::new(member.id, content);
+    if let Some(mood) = prompter.ask("Mood (e.g. great, ok, stressed, or 1-10; optional):")? {
+        update = update.with_mood(mood);
+    }
+    for blocker in prompter.ask_list("Blockers")? {
+        update.add_blocker(blocker);
+    }
+    for achievement in prompter.ask_list("Achievements")? {
+        update.add_achievement(achievement);
+    }
+    Ok(Some(update))
+}
+
+/// Lists the member's open blockers and asks which have been resolved.
+/// Returns the ids of the ones picked.
+pub fn prompt_resolved_blockers<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    open: &[Blocker],
+) -> Result<Vec<Uuid>> {
+    if open.is_empty() {
+        return Ok(Vec::new());
+    }
+    prompter.say("Still open:")?;
+    let now = Utc::now();
+    for (number, blocker) in open.iter().enumerate() {
+        prompter.say(&format!("  {}. {} (open {})", number + 1, blocker.description, format_age(blocker.age(now))))?;
+    }
+
+    loop {
+        let Some(answer) = prompter.ask("Resolved any? (numbers separated by commas, empty for none)")? else {
+            return Ok(Vec::new());
+        };
+        let picked: Option<Vec<Uuid>> = answer
+            .split(',')
+            .map(|n| n.trim().parse::<usize>().ok().filter(|n| (1..=open.len()).contains(n)).map(|n| open[n - 1].id))
+            .collect();
+        match picked {
+            Some(ids) => return Ok(ids),
+            None => prompter.say(&format!("Enter numbers between 1 and {}", open.len()))?,
+        }
+    }
+}
+
+pub async fn collect(
+    ctx: &AppContext,
+    mode: Option<String>,
+    member: Option<String>,
+    notify_slack: bool,
+    channel: Option<String>,
+) -> Result<()> {
+    let mode = mode.unwrap_or_else(|| "interactive".to_string());
+    if mode != "interactive" {
+        bail!("unknown status mode {:?}, expected interactive", mode);
+    }
+    if notify_slack && channel.is_none() {
+        bail!("--notify-slack needs a --channel to post the summary to");
+    }
+
+    let members = match member {
+        Some(query) => vec![find_member(ctx, &query)?],
+        None => {
+            let mut members = ctx.repo.list_team_members()?;
+            members.sort_by_key(|m| m.name.to_lowercase());
+            members
+        }
+    };
+    if members.is_empty() {
+        println!("No team members found; add one with `timeless add-user`");
+        return Ok(());
+    }
+
+    println!("{} Collecting status for {} member(s)", "✓".green(), members.len());
+    let stdin = io::stdin();
+    let mut prompter = Prompter::new(stdin.lock(), io::stdout());
+    let mut collected = Vec::new();
+
+    for member in &members {
+        let last = last_update_for(ctx, member)?;
+        match prompt_status_update(&mut prompter, member, last.as_ref())? {
+            Some(update) => {
+                let mut open = ctx.repo.list_open_blockers()?;
+                open.retain(|b| b.is_reported_by(member.id) || b.owner_id == Some(member.id));
+                ctx.repo.save_status_update(&update)?;
+                for id in prompt_resolved_blockers(&mut prompter, &open)? {
+                    ctx.repo.resolve_blocker(id, Utc::now())?;
+                }
+                println!("{} Saved update for {}", "✓".green(), member.name);
+                collected.push((member, update));
+            }
+            None if prompter.is_closed() => break,
+            None => println!("{} Skipped {}", "-".yellow(), member.name),
+        }
+    }
+
+    println!("\n{} Collected {} status update(s)", "✓".green(), collected.len());
+
+    if let (true, Some(channel)) = (notify_slack, channel) {
+        if collected.is_empty() {
+            return Ok(());
+        }
+        let summary: Vec<String> = collected
+            .iter()
+            .map(|(member, update)| {
+                let blockers = if update.has_blockers() {
+                    format!(" (blocked: {})", update.blockers.join("; "))
+                } else {
+                    String::new()
+                };
+                format!("{}: {}{}", member.name, update.content, blockers)
+            })
+            .collect();
+        SlackService::new().send_message(&channel, &summary.join("\n")).await?;
+        println!("{} Posted summary to {}", "✓".green(), channel.bold());
+    }
+    Ok(())
+}
+
+/// A status update posted without a terminal, from flags, a file or stdin.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatusSubmission {
+    pub member: Option<String>,
+    pub content: String,
+    pub mood: Option<String>,
+    pub blockers: Vec<String>,
+    pub achievements: Vec<String>,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+impl StatusSubmission {
+    /// Parses a JSON object, or a YAML-style list of `key: value` lines
+    /// with `- item` entries under `blockers:` and `achievements:`. A field
+    /// of the wrong type is reported by name.
+    pub fn parse(input: &str) -> Result<Self> {
+        let trimmed = input.trim_start();
+        let value = if trimmed.starts_with('{') {
+            serde_json::from_str(trimmed).context("invalid JSON status update")?
+        } else {
+            parse_key_values(input)?
+        };
+        let Value::Object(fields) = value else {
+            bail!("invalid status update: expected an object of fields");
+        };
+        // Check each field on its own so serde's error can be tied to its name
+        for (key, field) in &fields {
+            let single = Map::from_iter([(key.clone(), field.clone())]);
+            serde_json::from_value::<Self>(Value::Object(single))
+                .with_context(|| format!("invalid {} in status update", key))?;
+        }
+        serde_json::from_value(Value::Object(fields)).context("invalid status update")
+    }
+
+    /// Checks the submission and turns it into a `StatusUpdate` for `member`.
+    pub fn into_update(self, member: &TeamMember) -> Result<StatusUpdate> {
+        let content = self.content.trim();
+        if content.is_empty() {
+            bail!("status update content must not be empty");
+        }
+        if self.timestamp.is_some_and(|at| at > Utc::now()) {
+            bail!("status update timestamp is in the future");
+        }
+
+        let mut update = StatusUpdate::new(member.id, content.to_string());
+        if let Some(at) = self.timestamp {
+            update.timestamp = at;
+        }
+        if let Some(mood) = self.mood.map(|m| m.trim().to_string()).filter(|m| !m.is_empty()) {
+            update = update.with_mood(mood);
+        }
+        for blocker in self.blockers.iter().map(|b| b.trim()).filter(|b| !b.is_empty()) {
+            update.add_blocker(blocker.to_string());
+        }
+        for achievement in self.achievements.iter().map(|a| a.trim()).filter(|a| !a.is_empty()) {
+            update.add_achievement(achievement.to_string());
+        }
+        Ok(update)
+    }
+}
+
+const LIST_FIELDS: &[&str] = &["blockers", "achievements"];
+
+fn parse_key_values(input: &str) -> Result<Value> {
+    let unquote = |s: &str| {
+        let s = s.trim();
+        let quoted = s.len() >= 2
+            && ((s.starts_with('"') && s.ends_with('"')) || (s.starts_with('\'') && s.ends_with('\'')));
+        if quoted { s[1..s.len() - 1].to_string() } else { s.to_string() }
+    };
+
+    let mut fields = Map::new();
+    let mut list_key: Option<String> = None;
+    for (number, line) in input.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed == "---" {
+            continue;
+        }
+
+        if let Some(item) = trimmed.strip_prefix("- ").or_else(|| (trimmed == "-").then_some("")) {
+            let key = list_key.as_ref()
+                .ok_or_else(|| anyhow!("line {}: list item outside of a list", number + 1))?;
+            if let Some(Value::Array(items)) = fields.get_mut(key) {
+                items.push(Value::String(unquote(item)));
+            }
+            continue;
+        }
+
+        let (key, value) = trimmed.split_once(':')
+            .ok_or_else(|| anyhow!("line {}: expected `key: value`", number + 1))?;
+        let key = key.trim().to_string();
+        let value = value.trim();
+        let is_list = LIST_FIELDS.contains(&key.as_str());
+        if value.is_empty() && !is_list {
+            bail!("line {}: {} has no value", number + 1, key);
+        } else if value.is_empty() {
+            fields.insert(key.clone(), Value::Array(Vec::new()));
+            list_key = Some(key);
+        } else if is_list {
+            // `blockers: one thing` is shorthand for a single-entry list
+            fields.insert(key, Value::Array(vec![Value::String(unquote(value))]));
+            list_key = None;
+        } else {
+            fields.insert(key, Value::String(unquote(value)));
+            list_key = None;
+        }
+    }
+    Ok(Value::Object(fields))
+}
+
+pub async fn submit(
+    ctx: &AppContext,
+    member: Option<String>,
+    content: Option<String>,
+    blockers: Vec<String>,
+    achievements: Vec<String>,
+    mood: Option<String>,
+    from_file: Option<String>,
+) -> Result<()> {
+    // Structured input comes from --from-file, or from stdin when it is piped
+    // and the content was not given as a flag
+    let source = match from_file.as_deref() {
+        Some("-") => Some(read_stdin()?),
+        Some(path) => Some(std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path))?),
+        None if content.is_none() && !io::stdin().is_terminal() => Some(read_stdin()?),
+        None => None,
+    };
+    let mut submission = match source {
+        Some(text) => StatusSubmission::parse(&text)?,
+        None => StatusSubmission::default(),
+    };
+
+    // Flags take precedence over the structured input; lists are combined
+    if member.is_some() {
+        submission.member = member;
+    }
+    if let Some(content) = content {
+        submission.content = content;
+    }
+    if mood.is_some() {
+        submission.mood = mood;
+    }
+    submission.blockers.extend(blockers);
+    submission.achievements.extend(achievements);
+
+    let query = submission.member.clone()
+        .ok_or_else(|| anyhow!("no member given; pass --member or set `member` in the input"))?;
+    let member = find_member(ctx, &query)?;
+    let update = submission.into_update(&member)?;
+    ctx.repo.save_status_update(&update)?;
+
+    println!("{} Saved status update {} for {}", "✓".green(), update.id, member.name.bold());
+    Ok(())
+}
+
+fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).context("failed to read status update from stdin")?;
+    Ok(input)
+}
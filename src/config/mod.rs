@@ -1,15 +1,34 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{NaiveTime, Weekday};
+use log::warn;
 use serde::{Deserialize, Serialize};
-use anyhow::Result;
+use crate::models::BlockerSeverity;
+pub use crate::models::MetricsConfig;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Prefix for environment variable overrides. Nested keys are separated by a
+/// double underscore, e.g. `TIMELESS_APP__DATA_DIR` or
+/// `TIMELESS_TEAM__WORKING_HOURS__START`.
+pub const ENV_PREFIX: &str = "TIMELESS_";
+
+const LOG_LEVELS: &[&str] = &["off", "error", "warn", "info", "debug", "trace"];
+const DATA_FORMATS: &[&str] = &["json", "sqlite"];
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
     pub app: AppConfig,
     pub team: TeamConfig,
     pub claude: ClaudeConfig,
     pub storage: StorageConfig,
+    pub metrics: MetricsConfig,
+    pub escalation: EscalationConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AppConfig {
     pub name: String,
     pub version: String,
@@ -18,6 +37,7 @@ pub struct AppConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TeamConfig {
     pub name: String,
     pub timezone: String,
@@ -26,57 +46,393 @@ pub struct TeamConfig {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WorkingHours {
     pub start: String,
     pub end: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ClaudeConfig {
     pub enabled: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct StorageConfig {
     pub data_format: String,
+    /// How long a command waits for another process to release a data file
+    /// before giving up.
+    pub lock_timeout_ms: u64,
+}
+
+/// Rules evaluated by `timeless blockers escalate`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EscalationConfig {
+    pub rules: Vec<EscalationRule>,
+}
+
+/// An open blocker matching every condition set on a rule is sent to each of
+/// the rule's targets once. A rule needs at least one condition and one
+/// target.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EscalationRule {
+    pub name: String,
+    /// Only blockers at least this severe.
+    pub min_severity: Option<BlockerSeverity>,
+    /// Only blockers open for at least this many hours.
+    pub min_open_hours: Option<u32>,
+    /// Only blockers reported by at least this many members.
+    pub min_reporters: Option<usize>,
+    pub slack_channel: Option<String>,
+    pub email: Vec<String>,
+    /// Email the blocker's owner, when it has one.
+    pub notify_owner: bool,
+    /// Email the team leads: members whose role contains "lead", preferring
+    /// those assigned to the blocker's project.
+    pub notify_lead: bool,
+}
+
+/// Values supplied on the command line. They take precedence over both the
+/// config file and environment variables.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub data_dir: Option<String>,
+    pub log_level: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    #[error("invalid configuration:\n{}", format_field_errors(.0))]
+    Invalid(Vec<FieldError>),
+}
+
+fn format_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(|e| format!("  - {}", e))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        AppConfig {
+            name: "Smart Team Manager".to_string(),
+            version: "0.1.0".to_string(),
+            data_dir: "./data".to_string(),
+            log_level: "info".to_string(),
+        }
+    }
+}
+
+impl Default for TeamConfig {
+    fn default() -> Self {
+        TeamConfig {
+            name: "Engineering Team".to_string(),
+            timezone: "UTC".to_string(),
+            working_hours: WorkingHours::default(),
+            working_days: vec![
+                "Monday".to_string(),
+                "Tuesday".to_string(),
+                "Wednesday".to_string(),
+                "Thursday".to_string(),
+                "Friday".to_string(),
+            ],
+        }
+    }
 }
 
-impl Default for Config {
+impl Default for WorkingHours {
     fn default() -> Self {
-        Config {
-            app: AppConfig {
-                name: "Smart Team Manager".to_string(),
-                version: "0.1.0".to_string(),
-                data_dir: "./data".to_string(),
-                log_level: "info".to_string(),
-            },
-            team: TeamConfig {
-                name: "Engineering Team".to_string(),
-                timezone: "UTC".to_string(),
-                working_hours: WorkingHours {
-                    start: "09:00".to_string(),
-                    end: "17:00".to_string(),
-                },
-                working_days: vec![
-                    "Monday".to_string(),
-                    "Tuesday".to_string(),
-                    "Wednesday".to_string(),
-                    "Thursday".to_string(),
-                    "Friday".to_string(),
-                ],
-            },
-            claude: ClaudeConfig {
-                enabled: true,
-            },
-            storage: StorageConfig {
-                data_format: "json".to_string(),
-            },
+        WorkingHours {
+            start: "09:00".to_string(),
+            end: "17:00".to_string(),
+        }
+    }
+}
+
+impl Default for ClaudeConfig {
+    fn default() -> Self {
+        ClaudeConfig {
+            enabled: true,
+        }
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        StorageConfig {
+            data_format: "json".to_string(),
+            lock_timeout_ms: 5000,
+        }
+    }
+}
+
+impl AppConfig {
+    /// The data directory with a leading `~` expanded to the user's home.
+    pub fn data_path(&self) -> PathBuf {
+        match self.data_dir.strip_prefix("~") {
+            Some(rest) if rest.is_empty() || rest.starts_with('/') => {
+                match std::env::var_os("HOME") {
+                    Some(home) => PathBuf::from(home).join(rest.trim_start_matches('/')),
+                    None => PathBuf::from(&self.data_dir),
+                }
+            }
+            _ => PathBuf::from(&self.data_dir),
+        }
+    }
+}
+
+impl StorageConfig {
+    pub fn lock_timeout(&self) -> Duration {
+        Duration::from_millis(self.lock_timeout_ms)
+    }
+}
+
+impl Config {
+    pub fn validate(&self) -> std::result::Result<(), ConfigError> {
+        let mut errors = Vec::new();
+        let mut error = |field: &str, message: String| {
+            errors.push(FieldError { field: field.to_string(), message });
+        };
+
+        if self.app.data_dir.trim().is_empty() {
+            error("app.data_dir", "must not be empty".to_string());
+        }
+        if !LOG_LEVELS.contains(&self.app.log_level.to_lowercase().as_str()) {
+            error(
+                "app.log_level",
+                format!("expected one of {}, got {:?}", LOG_LEVELS.join(", "), self.app.log_level),
+            );
+        }
+
+        if self.team.name.trim().is_empty() {
+            error("team.name", "must not be empty".to_string());
+        }
+
+        let start = parse_time(&self.team.working_hours.start);
+        let end = parse_time(&self.team.working_hours.end);
+        if start.is_none() {
+            error(
+                "team.working_hours.start",
+                format!("expected HH:MM, got {:?}", self.team.working_hours.start),
+            );
+        }
+        if end.is_none() {
+            error(
+                "team.working_hours.end",
+                format!("expected HH:MM, got {:?}", self.team.working_hours.end),
+            );
+        }
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                error(
+                    "team.working_hours",
+                    format!("start ({}) must be before end ({})", self.team.working_hours.start, self.team.working_hours.end),
+                );
+            }
+        }
+
+        if self.team.working_days.is_empty() {
+            error("team.working_days", "must list at least one day".to_string());
+        }
+        for (i, day) in self.team.working_days.iter().enumerate() {
+            if day.parse::<Weekday>().is_err() {
+                error(
+                    &format!("team.working_days[{}]", i),
+                    format!("expected a weekday name such as \"Monday\", got {:?}", day),
+                );
+            }
+        }
+
+        if !DATA_FORMATS.contains(&self.storage.data_format.as_str()) {
+            error(
+                "storage.data_format",
+                format!("expected one of {}, got {:?}", DATA_FORMATS.join(", "), self.storage.data_format),
+            );
+        }
+
+        if self.storage.lock_timeout_ms == 0 {
+            error("storage.lock_timeout_ms", "must be greater than zero".to_string());
+        }
+
+        let metrics = &self.metrics;
+        for (field, weight) in [
+            ("metrics.satisfaction_weight", metrics.satisfaction_weight),
+            ("metrics.velocity_weight", metrics.velocity_weight),
+            ("metrics.blocker_weight", metrics.blocker_weight),
+        ] {
+            if !(weight >= 0.0 && weight.is_finite()) {
+                error(field, format!("must be zero or positive, got {}", weight));
+            }
+        }
+        if metrics.satisfaction_weight + metrics.velocity_weight + metrics.blocker_weight <= 0.0 {
+            error("metrics", "at least one health score weight must be positive".to_string());
+        }
+        if !(metrics.velocity_cap > 0.0 && metrics.velocity_cap.is_finite()) {
+            error("metrics.velocity_cap", format!("must be greater than zero, got {}", metrics.velocity_cap));
+        }
+        if !(0.0..=10.0).contains(&metrics.min_satisfaction) {
+            error("metrics.min_satisfaction", format!("must be between 0 and 10, got {}", metrics.min_satisfaction));
+        }
+        if !(metrics.min_velocity >= 0.0 && metrics.min_velocity.is_finite()) {
+            error("metrics.min_velocity", format!("must be zero or positive, got {}", metrics.min_velocity));
+        }
+        if !(metrics.max_blocker_ratio >= 0.0 && metrics.max_blocker_ratio.is_finite()) {
+            error("metrics.max_blocker_ratio", format!("must be zero or positive, got {}", metrics.max_blocker_ratio));
+        }
+
+        for (i, rule) in self.escalation.rules.iter().enumerate() {
+            let field = format!("escalation.rules[{}]", i);
+            if rule.name.trim().is_empty() {
+                error(&format!("{}.name", field), "must not be empty".to_string());
+            } else if self.escalation.rules[..i].iter().any(|r| r.name == rule.name) {
+                error(&format!("{}.name", field), format!("duplicate rule name {:?}", rule.name));
+            }
+            if rule.min_severity.is_none() && rule.min_open_hours.is_none() && rule.min_reporters.is_none() {
+                error(&field, "needs at least one of min_severity, min_open_hours or min_reporters".to_string());
+            }
+            if rule.slack_channel.as_deref().is_none_or(|c| c.trim().is_empty())
+                && rule.email.is_empty()
+                && !rule.notify_owner
+                && !rule.notify_lead
+            {
+                error(&field, "needs a slack_channel, an email recipient, notify_owner or notify_lead".to_string());
+            }
+            if rule.min_reporters == Some(0) {
+                error(&format!("{}.min_reporters", field), "must be at least 1".to_string());
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(errors))
         }
     }
+
+    fn apply_overrides(&mut self, overrides: &ConfigOverrides) {
+        if let Some(data_dir) = &overrides.data_dir {
+            self.app.data_dir = data_dir.clone();
+        }
+        if let Some(log_level) = &overrides.log_level {
+            self.app.log_level = log_level.clone();
+        }
+    }
+}
+
+fn parse_time(value: &str) -> Option<NaiveTime> {
+    NaiveTime::parse_from_str(value, "%H:%M").ok()
 }
 
-pub fn load_config(_path: &str) -> Result<Config> {
-    // TODO: Implement actual config loading from TOML file
-    // For now, return default config
-    Ok(Config::default())
-}
\ No newline at end of file
+/// Loads the config file at `path`, then layers `TIMELESS_*` environment
+/// variables and command-line overrides on top. A missing file falls back to
+/// the built-in defaults.
+pub fn load_config(path: &str, overrides: &ConfigOverrides) -> Result<Config> {
+    let contents = if Path::new(path).exists() {
+        Some(std::fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path))?)
+    } else {
+        warn!("Config file {} not found, using defaults", path);
+        None
+    };
+
+    resolve_config(contents.as_deref(), std::env::vars(), overrides)
+        .with_context(|| format!("failed to load config from {}", path))
+}
+
+/// Builds a validated `Config` from optional TOML contents, a set of
+/// environment variables and command-line overrides, in increasing order of
+/// precedence.
+pub fn resolve_config<I>(contents: Option<&str>, env: I, overrides: &ConfigOverrides) -> Result<Config>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let config: Config = match contents {
+        Some(contents) => toml::from_str(contents).context("failed to parse config TOML")?,
+        None => Config::default(),
+    };
+
+    let mut value = toml::Value::try_from(&config)?;
+    apply_env_overrides(&mut value, env)?;
+    let mut config: Config = value.try_into()?;
+
+    config.apply_overrides(overrides);
+    config.validate()?;
+    Ok(config)
+}
+
+fn apply_env_overrides<I>(value: &mut toml::Value, env: I) -> Result<()>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    for (name, raw) in env {
+        let Some(path) = name.strip_prefix(ENV_PREFIX) else {
+            continue;
+        };
+        if !path.contains("__") {
+            continue;
+        }
+
+        let segments: Vec<String> = path.split("__").map(|s| s.to_lowercase()).collect();
+        set_value(value, &segments, &raw)
+            .with_context(|| format!("invalid environment override {}", name))?;
+    }
+    Ok(())
+}
+
+fn set_value(root: &mut toml::Value, segments: &[String], raw: &str) -> Result<()> {
+    let field = segments.join(".");
+    let (last, parents) = segments.split_last()
+        .ok_or_else(|| anyhow!("empty configuration key"))?;
+
+    let mut current = root;
+    for segment in parents {
+        current = current
+            .get_mut(segment.as_str())
+            .ok_or_else(|| anyhow!("unknown configuration key {}", field))?;
+    }
+
+    let table = current
+        .as_table_mut()
+        .ok_or_else(|| anyhow!("{} is not a configuration section", field))?;
+    let existing = table
+        .get(last.as_str())
+        .ok_or_else(|| anyhow!("unknown configuration key {}", field))?;
+
+    let parsed = match existing {
+        toml::Value::String(_) => toml::Value::String(raw.to_string()),
+        toml::Value::Boolean(_) => toml::Value::Boolean(raw.trim().parse()
+            .map_err(|_| anyhow!("{}: expected true or false, got {:?}", field, raw))?),
+        toml::Value::Integer(_) => toml::Value::Integer(raw.trim().parse()
+            .map_err(|_| anyhow!("{}: expected an integer, got {:?}", field, raw))?),
+        toml::Value::Float(_) => toml::Value::Float(raw.trim().parse()
+            .map_err(|_| anyhow!("{}: expected a number, got {:?}", field, raw))?),
+        toml::Value::Array(_) => toml::Value::Array(raw
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| toml::Value::String(s.to_string()))
+            .collect()),
+        _ => bail!("{} cannot be set from an environment variable", field),
+    };
+
+    table.insert(last.clone(), parsed);
+    Ok(())
+}
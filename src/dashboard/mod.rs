@@ -0,0 +1,117 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+use crate::config::MetricsConfig;
+use crate::models::{AIDecision, BlockerSeverity, Concern, ProjectStatus, StatusUpdate, TeamMember, TeamMetrics};
+use crate::storage::{Storage, TeamRepository};
+
+pub mod ui;
+
+/// AI decisions shown on the dashboard.
+pub const RECENT_DECISIONS: usize = 5;
+
+/// Everything the dashboard shows, read from the repository in one pass.
+#[derive(Debug, Clone)]
+pub struct DashboardData {
+    pub team_name: String,
+    pub loaded_at: DateTime<Utc>,
+    pub roster: Vec<RosterEntry>,
+    pub open_blockers: Vec<OpenBlocker>,
+    pub metrics: Option<TeamMetrics>,
+    pub health_score: Option<f32>,
+    pub concerns: Vec<Concern>,
+    pub projects: Vec<ActiveProject>,
+    pub decisions: Vec<AIDecision>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RosterEntry {
+    pub member: TeamMember,
+    pub latest: Option<StatusUpdate>,
+}
+
+/// An unresolved blocker with its reporter's name.
+#[derive(Debug, Clone)]
+pub struct OpenBlocker {
+    pub member: String,
+    pub text: String,
+    pub severity: BlockerSeverity,
+    pub since: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActiveProject {
+    pub name: String,
+    pub members: Vec<String>,
+}
+
+impl DashboardData {
+    pub fn load<S: Storage>(
+        repo: &TeamRepository<S>,
+        team_name: &str,
+        health: &MetricsConfig,
+        now: DateTime<Utc>,
+    ) -> Result<Self> {
+        let mut members = repo.list_team_members()?;
+        members.sort_by_key(|m| m.name.to_lowercase());
+
+        let names: HashMap<Uuid, String> = members.iter().map(|m| (m.id, m.name.clone())).collect();
+        let open_blockers = repo.list_open_blockers()?
+            .into_iter()
+            .map(|blocker| OpenBlocker {
+                member: names.get(&blocker.reporter_id).cloned().unwrap_or_else(|| "unknown".to_string()),
+                text: blocker.description,
+                severity: blocker.severity,
+                since: blocker.opened_at,
+            })
+            .collect();
+
+        let mut roster = Vec::new();
+        for member in members {
+            let latest = repo.get_status_updates_for_member(member.id)?
+                .into_iter()
+                .max_by_key(|update| update.timestamp);
+            roster.push(RosterEntry { member, latest });
+        }
+
+        let today = now.date_naive();
+        let mut projects = Vec::new();
+        let mut all_projects = repo.list_projects()?;
+        all_projects.retain(|p| p.status == ProjectStatus::Active && !p.is_archived());
+        all_projects.sort_by_key(|p| p.name.to_lowercase());
+        for project in all_projects {
+            let members = repo.get_members_on_project(project.id, today)?
+                .into_iter()
+                .map(|(member, assignment)| format!("{} {}%", member.name, assignment.allocation))
+                .collect();
+            projects.push(ActiveProject { name: project.name, members });
+        }
+
+        let metrics = repo.get_latest_team_metrics()?;
+        Ok(DashboardData {
+            team_name: team_name.to_string(),
+            loaded_at: now,
+            roster,
+            open_blockers,
+            health_score: metrics.as_ref().map(|m| m.health_score_with(health)),
+            concerns: metrics.as_ref().map(|m| m.concerns(health)).unwrap_or_default(),
+            metrics,
+            projects,
+            decisions: repo.get_recent_ai_decisions(RECENT_DECISIONS)?,
+        })
+    }
+}
+
+/// A short, coarse age such as `5m`, `3h` or `2d`.
+pub fn format_age(age: Duration) -> String {
+    if age < Duration::minutes(1) {
+        "now".to_string()
+    } else if age < Duration::hours(1) {
+        format!("{}m", age.num_minutes())
+    } else if age < Duration::days(1) {
+        format!("{}h", age.num_hours())
+    } else {
+        format!("{}d", age.num_days())
+    }
+}
@@ -0,0 +1,204 @@
+use chrono::Utc;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, List, ListItem, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::Frame;
+use crate::dashboard::{format_age, DashboardData};
+use crate::metrics::mood_score;
+use crate::models::BlockerSeverity;
+
+/// What the user has changed on screen, kept across refreshes.
+#[derive(Debug, Default)]
+pub struct DashboardState {
+    pub roster: TableState,
+    /// Shown in the footer: what is being watched, or the last load error.
+    pub status: String,
+}
+
+impl DashboardState {
+    pub fn select_next(&mut self, rows: usize) {
+        if rows == 0 {
+            return;
+        }
+        let next = self.roster.selected().map_or(0, |i| (i + 1).min(rows - 1));
+        self.roster.select(Some(next));
+    }
+
+    pub fn select_previous(&mut self) {
+        let previous = self.roster.selected().map_or(0, |i| i.saturating_sub(1));
+        self.roster.select(Some(previous));
+    }
+}
+
+/// Colour for a 0-10 score: green when good, yellow when middling, red when low.
+fn score_color(score: f32) -> Color {
+    if score >= 7.0 {
+        Color::Green
+    } else if score >= 5.0 {
+        Color::Yellow
+    } else {
+        Color::Red
+    }
+}
+
+pub fn draw(frame: &mut Frame, data: &DashboardData, state: &mut DashboardState) {
+    let [header, top, bottom, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Percentage(55),
+        Constraint::Min(8),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [roster, blockers] = Layout::horizontal([Constraint::Percentage(62), Constraint::Percentage(38)]).areas(top);
+    let [metrics, projects, decisions] = Layout::horizontal([
+        Constraint::Percentage(30),
+        Constraint::Percentage(30),
+        Constraint::Percentage(40),
+    ])
+    .areas(bottom);
+
+    draw_header(frame, header, data);
+    draw_roster(frame, roster, data, state);
+    draw_blockers(frame, blockers, data);
+    draw_metrics(frame, metrics, data);
+    draw_projects(frame, projects, data);
+    draw_decisions(frame, decisions, data);
+
+    let help = " q quit  r refresh  ↑/↓ scroll ";
+    let footer_line = Line::from(vec![help.reversed(), Span::raw(" "), Span::raw(state.status.as_str()).dim()]);
+    frame.render_widget(Paragraph::new(footer_line), footer);
+}
+
+fn draw_header(frame: &mut Frame, area: Rect, data: &DashboardData) {
+    let mut spans = vec![format!(" {} ", data.team_name).bold().reversed(), Span::raw("  ")];
+    match data.health_score {
+        Some(score) => {
+            spans.push(Span::raw("health "));
+            spans.push(Span::styled(format!("{:.1}/10", score), Style::new().fg(score_color(score)).bold()));
+        }
+        None => spans.push(Span::raw("no metrics yet").dim()),
+    }
+    spans.push(Span::raw(format!("  {} members  {} open blockers", data.roster.len(), data.open_blockers.len())));
+    spans.push(Span::raw(format!("  updated {}", data.loaded_at.with_timezone(&chrono::Local).format("%H:%M:%S"))).dim());
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn draw_roster(frame: &mut Frame, area: Rect, data: &DashboardData, state: &mut DashboardState) {
+    let now = Utc::now();
+    let rows: Vec<Row> = data.roster.iter().map(|entry| {
+        let (mood, age, content) = match &entry.latest {
+            Some(update) => {
+                let mood = match update.mood.as_deref() {
+                    Some(mood) => {
+                        let style = mood_score(mood).map_or(Style::new(), |s| Style::new().fg(score_color(s)));
+                        Cell::from(mood.to_string()).style(style)
+                    }
+                    None => Cell::from("-"),
+                };
+                (mood, format_age(now - update.timestamp), update.content.clone())
+            }
+            None => (Cell::from("-"), "-".to_string(), "no updates yet".to_string()),
+        };
+        Row::new(vec![
+            Cell::from(entry.member.name.clone()),
+            Cell::from(entry.member.role.clone()).dim(),
+            mood,
+            Cell::from(age),
+            Cell::from(content),
+        ])
+    }).collect();
+
+    let table = Table::new(rows, [
+        Constraint::Length(18),
+        Constraint::Length(12),
+        Constraint::Length(10),
+        Constraint::Length(5),
+        Constraint::Fill(1),
+    ])
+    .header(Row::new(["Name", "Role", "Mood", "Age", "Latest update"]).bold())
+    .block(Block::bordered().title(" Roster "))
+    .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+    frame.render_stateful_widget(table, area, &mut state.roster);
+}
+
+fn draw_blockers(frame: &mut Frame, area: Rect, data: &DashboardData) {
+    let now = Utc::now();
+    let items: Vec<ListItem> = if data.open_blockers.is_empty() {
+        vec![ListItem::new("No open blockers".dim())]
+    } else {
+        data.open_blockers.iter().map(|blocker| {
+            let mut spans = vec![Span::styled(format!("{:>4} ", format_age(now - blocker.since)), Style::new().fg(Color::Red))];
+            if blocker.severity >= BlockerSeverity::High {
+                spans.push(Span::styled(format!("[{}] ", blocker.severity), Style::new().fg(Color::Red).bold()));
+            }
+            spans.extend([
+                blocker.member.clone().bold(),
+                Span::raw(format!(": {}", blocker.text)),
+            ]);
+            ListItem::new(Line::from(spans))
+        }).collect()
+    };
+    let title = format!(" Open blockers ({}) ", data.open_blockers.len());
+    frame.render_widget(List::new(items).block(Block::bordered().title(title)), area);
+}
+
+fn draw_metrics(frame: &mut Frame, area: Rect, data: &DashboardData) {
+    let mut lines = Vec::new();
+    match &data.metrics {
+        Some(metrics) => {
+            lines.push(Line::from(format!("As of {}", metrics.date.format("%Y-%m-%d"))).dim());
+            if let Some(score) = data.health_score {
+                lines.push(Line::from(vec![
+                    Span::raw("Health        "),
+                    Span::styled(format!("{:.1}/10", score), Style::new().fg(score_color(score)).bold()),
+                ]));
+            }
+            if let Some(satisfaction) = metrics.average_satisfaction {
+                lines.push(Line::from(format!("Satisfaction  {:.1}/10", satisfaction)));
+            }
+            if let Some(velocity) = metrics.velocity {
+                lines.push(Line::from(format!("Velocity      {:.1}", velocity)));
+            }
+            lines.push(Line::from(format!("Active        {}", metrics.active_members)));
+            lines.push(Line::from(format!("Blockers      {}", metrics.blockers_count)));
+            for concern in &data.concerns {
+                lines.push(Line::from(format!("! {}", concern)).fg(Color::Red));
+            }
+        }
+        None => lines.push(Line::from("Run `timeless metrics compute`").dim()),
+    }
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: true }).block(Block::bordered().title(" Metrics "));
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_projects(frame: &mut Frame, area: Rect, data: &DashboardData) {
+    let items: Vec<ListItem> = if data.projects.is_empty() {
+        vec![ListItem::new("No active projects".dim())]
+    } else {
+        data.projects.iter().map(|project| {
+            let members = if project.members.is_empty() { "nobody assigned".to_string() } else { project.members.join(", ") };
+            ListItem::new(vec![Line::from(project.name.clone().bold()), Line::from(format!("  {}", members)).dim()])
+        }).collect()
+    };
+    frame.render_widget(List::new(items).block(Block::bordered().title(" Active projects ")), area);
+}
+
+fn draw_decisions(frame: &mut Frame, area: Rect, data: &DashboardData) {
+    let items: Vec<ListItem> = if data.decisions.is_empty() {
+        vec![ListItem::new("No AI decisions recorded".dim())]
+    } else {
+        data.decisions.iter().map(|decision| {
+            let confidence = format!("{:.0}%", decision.confidence * 100.0);
+            ListItem::new(vec![
+                Line::from(vec![
+                    decision.decision_type.clone().bold(),
+                    Span::raw(format!(" {} ", decision.created_at.format("%m-%d %H:%M"))).dim(),
+                    Span::styled(confidence, Style::new().fg(if decision.is_high_confidence() { Color::Green } else { Color::Yellow })),
+                ]),
+                Line::from(format!("  {}", decision.recommendation)),
+            ])
+        }).collect()
+    };
+    frame.render_widget(List::new(items).block(Block::bordered().title(" Recent AI decisions ")), area);
+}
@@ -0,0 +1,149 @@
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::fmt;
+use uuid::Uuid;
+use crate::config::EscalationRule;
+use crate::dashboard::format_age;
+use crate::models::{Blocker, TeamMember};
+use crate::storage::{Storage, TeamRepository};
+
+/// Where an escalation is sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EscalationTarget {
+    Slack(String),
+    Email(String),
+}
+
+impl fmt::Display for EscalationTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EscalationTarget::Slack(channel) => write!(f, "slack:{}", channel),
+            EscalationTarget::Email(address) => write!(f, "email:{}", address),
+        }
+    }
+}
+
+/// An escalation that matches a rule but has not been sent yet.
+#[derive(Debug, Clone)]
+pub struct PendingEscalation {
+    pub blocker: Blocker,
+    pub rule: EscalationRule,
+    pub target: EscalationTarget,
+}
+
+/// Whether an open blocker meets every condition set on the rule.
+pub fn rule_matches(rule: &EscalationRule, blocker: &Blocker, now: DateTime<Utc>) -> bool {
+    blocker.is_open()
+        && rule.min_severity.is_none_or(|severity| blocker.severity >= severity)
+        && rule.min_open_hours.is_none_or(|hours| blocker.age(now) >= Duration::hours(hours.into()))
+        && rule.min_reporters.is_none_or(|count| blocker.reporters().count() >= count)
+}
+
+/// The rule's fixed targets: its Slack channel and email recipients.
+pub fn targets(rule: &EscalationRule) -> Vec<EscalationTarget> {
+    let channel = rule.slack_channel.iter()
+        .map(|c| c.trim())
+        .filter(|c| !c.is_empty())
+        .map(|c| EscalationTarget::Slack(c.to_string()));
+    let emails = rule.email.iter().map(|address| EscalationTarget::Email(address.trim().to_string()));
+    channel.chain(emails).collect()
+}
+
+/// The rule's fixed targets plus the blocker's owner and the team leads
+/// when the rule asks for them, without duplicates.
+pub fn targets_for<S: Storage>(
+    repo: &TeamRepository<S>,
+    rule: &EscalationRule,
+    blocker: &Blocker,
+    now: DateTime<Utc>,
+) -> Result<Vec<EscalationTarget>> {
+    let mut targets = targets(rule);
+    if rule.notify_owner {
+        if let Some(owner) = blocker.owner_id.map(|id| repo.get_team_member(id)).transpose()?.flatten() {
+            targets.push(EscalationTarget::Email(owner.email));
+        }
+    }
+    if rule.notify_lead {
+        targets.extend(leads(repo, blocker, now)?.into_iter().map(|lead| EscalationTarget::Email(lead.email)));
+    }
+
+    let mut unique = Vec::new();
+    for target in targets {
+        if !unique.contains(&target) {
+            unique.push(target);
+        }
+    }
+    Ok(unique)
+}
+
+/// Members whose role contains "lead": those on the blocker's project if it
+/// has any, otherwise everyone in the team with such a role.
+fn leads<S: Storage>(repo: &TeamRepository<S>, blocker: &Blocker, now: DateTime<Utc>) -> Result<Vec<TeamMember>> {
+    let is_lead = |member: &TeamMember| member.role.to_lowercase().contains("lead");
+    if let Some(project_id) = blocker.project_id {
+        let on_project: Vec<TeamMember> = repo.get_members_on_project(project_id, now.date_naive())?
+            .into_iter()
+            .map(|(member, _)| member)
+            .filter(is_lead)
+            .collect();
+        if !on_project.is_empty() {
+            return Ok(on_project);
+        }
+    }
+    Ok(repo.list_team_members()?.into_iter().filter(is_lead).collect())
+}
+
+/// Every (blocker, rule, target) that matches and was not already sent.
+pub fn pending<S: Storage>(
+    repo: &TeamRepository<S>,
+    rules: &[EscalationRule],
+    now: DateTime<Utc>,
+) -> Result<Vec<PendingEscalation>> {
+    let mut pending = Vec::new();
+    if rules.is_empty() {
+        return Ok(pending);
+    }
+    for blocker in repo.list_open_blockers()? {
+        let matching: Vec<&EscalationRule> = rules.iter().filter(|rule| rule_matches(rule, &blocker, now)).collect();
+        if matching.is_empty() {
+            continue;
+        }
+        let sent = repo.get_escalations_for_blocker(blocker.id)?;
+        for rule in matching {
+            for target in targets_for(repo, rule, &blocker, now)? {
+                let key = target.to_string();
+                if sent.iter().any(|e| e.rule == rule.name && e.target == key) {
+                    continue;
+                }
+                pending.push(PendingEscalation { blocker: blocker.clone(), rule: rule.clone(), target });
+            }
+        }
+    }
+    Ok(pending)
+}
+
+/// The text sent for an escalation. `names` maps member and project ids to
+/// display names.
+pub fn message(escalation: &PendingEscalation, names: &HashMap<Uuid, String>, now: DateTime<Utc>) -> String {
+    let blocker = &escalation.blocker;
+    let name = |id: &Uuid| names.get(id).cloned().unwrap_or_else(|| id.to_string()[..8].to_string());
+
+    let mut lines = vec![
+        format!("Blocker escalated by rule \"{}\": {}", escalation.rule.name, blocker.description),
+        format!(
+            "Severity {}, open {}, reported by {}",
+            blocker.severity,
+            format_age(blocker.age(now)),
+            blocker.reporters().map(|id| name(&id)).collect::<Vec<_>>().join(", "),
+        ),
+    ];
+    lines.push(format!("Owner: {}", blocker.owner_id.as_ref().map(name).unwrap_or_else(|| "nobody".to_string())));
+    if let Some(project) = &blocker.project_id {
+        lines.push(format!("Project: {}", name(project)));
+    }
+    if let Some(ticket) = &blocker.ticket {
+        lines.push(format!("Ticket: {}", ticket));
+    }
+    lines.join("\n")
+}
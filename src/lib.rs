@@ -1,8 +1,12 @@
 pub mod cli;
 pub mod config;
+pub mod dashboard;
+pub mod escalation;
 pub mod models;
 pub mod storage;
 pub mod claude;
+pub mod metrics;
+pub mod reports;
 pub mod services;
 
 pub use anyhow::Result;
@@ -1,5 +1,7 @@
 use clap::{Parser, Subcommand};
 use anyhow::Result;
+use timeless::cli::{error, AppContext};
+use timeless::config::ConfigOverrides;
 
 #[derive(Parser)]
 #[command(name = "timeless")]
@@ -8,9 +10,17 @@ struct Cli {
     #[command(subcommand)]
     command: Commands,
     
-    #[arg(short, long, default_value = "./config/config.toml")]
+    #[arg(short, long, env = "TIMELESS_CONFIG_PATH", default_value = "./config/config.toml")]
     config: String,
     
+    /// Override the data directory from the config file
+    #[arg(long, global = true)]
+    data_dir: Option<String>,
+    
+    /// Override the log level from the config file
+    #[arg(long, global = true)]
+    log_level: Option<String>,
+    
     #[arg(short, long)]
     verbose: bool,
 }
@@ -30,33 +40,127 @@ enum Commands {
         name: String,
         #[arg(short, long)]
         email: String,
+        #[arg(short, long, default_value = "Team Member")]
+        role: String,
         #[arg(short, long)]
         slack_id: Option<String>,
     },
+    /// Manage team members
+    Member {
+        #[command(subcommand)]
+        action: MemberAction,
+    },
+    /// Manage projects
+    Project {
+        #[command(subcommand)]
+        action: ProjectAction,
+    },
+    /// Assign a member to a project
+    Assign {
+        /// Member id, email, Slack ID or name
+        #[arg(short, long)]
+        member: String,
+        /// Project id or name
+        #[arg(short, long)]
+        project: String,
+        /// Share of the member's time, in percent (100 for new assignments)
+        #[arg(short, long)]
+        allocation: Option<u8>,
+        /// First day of the assignment (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        start: Option<String>,
+        /// Last day of the assignment (YYYY-MM-DD); open-ended when left out
+        #[arg(long)]
+        end: Option<String>,
+    },
+    /// Remove a member from a project
+    Unassign {
+        /// Member id, email, Slack ID or name
+        #[arg(short, long)]
+        member: String,
+        /// Project id or name
+        #[arg(short, long)]
+        project: String,
+    },
     /// Run status collection
     Status {
+        #[command(subcommand)]
+        action: Option<StatusAction>,
         #[arg(short, long)]
         mode: Option<String>,
+        /// Only collect from this member (id, email, Slack ID or name)
+        #[arg(long)]
+        member: Option<String>,
         #[arg(short, long)]
         notify_slack: bool,
+        /// Slack channel for the summary posted with --notify-slack
+        #[arg(short, long)]
+        channel: Option<String>,
     },
     /// Generate team reports
     Report {
+        /// daily, weekly, sprint or monthly
         #[arg(short, long)]
         report_type: String,
+        /// Write the report to this file instead of stdout; the format's
+        /// extension is added when the name has none
         #[arg(short, long)]
         output: Option<String>,
+        /// markdown (default), html or json
+        #[arg(short, long)]
+        format: Option<String>,
+        /// Add a narrative summary written by Claude CLI
+        #[arg(long)]
+        ai_summary: bool,
+    },
+    /// Track blockers reported in status updates
+    Blockers {
+        #[command(subcommand)]
+        action: BlockersAction,
+    },
+    /// Compute and inspect team metrics
+    Metrics {
+        #[command(subcommand)]
+        action: MetricsAction,
     },
+    /// Live overview of the team that refreshes as the data changes
+    Dashboard,
     /// Test MCP server connections
     TestMcp,
     /// Show system status
     Health,
+    /// Upgrade stored data files to the current schema version
+    Migrate {
+        /// Show the changes without writing them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Export all team data to a single archive file
+    Export {
+        /// Destination file (defaults to timeless-export-<date>.json)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Import team data from an archive produced by `export`
+    Import {
+        /// Archive file to import
+        input: String,
+        /// "merge" to match records by id, "replace" to overwrite collections
+        #[arg(short, long, default_value = "merge")]
+        mode: String,
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
     /// Query Jira for work items using prompts
     QueryJira {
         #[arg(short, long)]
         projects: Vec<String>,
         #[arg(short = 'e', long, default_value = "week")]
         period: String,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
     },
     /// Send message to Slack using prompts
     SlackMessage {
@@ -69,49 +173,330 @@ enum Commands {
     TeamStatus {
         #[arg(short, long)]
         channel: String,
+        /// Members by id, email, Slack ID or name
         #[arg(short, long)]
         members: Vec<String>,
     },
 }
 
+#[derive(Subcommand)]
+enum BlockersAction {
+    /// List blockers, oldest first
+    List {
+        /// Only show blockers that are still open
+        #[arg(long)]
+        open: bool,
+        /// Only show blockers open at least this long, e.g. 3d or 12h
+        #[arg(long)]
+        older_than: Option<String>,
+        /// Only show blockers reported by or owned by this member
+        #[arg(short, long)]
+        member: Option<String>,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Mark a blocker as resolved
+    Resolve {
+        /// Blocker id or id prefix
+        blocker: String,
+    },
+    /// Set a blocker's owner, severity, project or ticket
+    Edit {
+        /// Blocker id or id prefix
+        blocker: String,
+        #[arg(short, long)]
+        owner: Option<String>,
+        /// low, medium, high or critical
+        #[arg(short, long)]
+        severity: Option<String>,
+        #[arg(short, long)]
+        project: Option<String>,
+        /// Linked ticket key, e.g. PROJ-123 (empty to clear)
+        #[arg(short, long)]
+        ticket: Option<String>,
+    },
+    /// Notify people about blockers matching the [escalation] rules
+    Escalate {
+        /// Show what would be sent without sending or recording anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum MetricsAction {
+    /// Derive metrics snapshots from the status updates posted since a date
+    Compute {
+        /// YYYY-MM-DD or an age such as 14d (defaults to 7d)
+        #[arg(short, long)]
+        since: Option<String>,
+        /// Snapshot period: daily or weekly
+        #[arg(short, long, default_value = "daily")]
+        period: String,
+        /// Show the snapshots without saving them
+        #[arg(long)]
+        dry_run: bool,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Show weekly trends in velocity, satisfaction and blockers per member
+    Trends {
+        /// Number of weeks to show, ending with the current one
+        #[arg(short, long, default_value_t = 8)]
+        weeks: usize,
+        /// Weeks in the moving average
+        #[arg(long, default_value_t = 3)]
+        window: usize,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum MemberAction {
+    /// List team members
+    List {
+        /// Only show members with this role
+        #[arg(short, long)]
+        role: Option<String>,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Show a single team member
+    Show {
+        /// Member id, email, Slack ID or name
+        member: String,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Edit a team member
+    Edit {
+        /// Member id, email, Slack ID or name
+        member: String,
+        #[arg(short, long)]
+        name: Option<String>,
+        #[arg(short, long)]
+        email: Option<String>,
+        #[arg(short, long)]
+        role: Option<String>,
+        /// New Slack ID; pass an empty string to clear it
+        #[arg(short, long)]
+        slack_id: Option<String>,
+    },
+    /// Remove a team member
+    Remove {
+        /// Member id, email, Slack ID or name
+        member: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum StatusAction {
+    /// Save a status update without prompting
+    Submit {
+        /// Member id, email, Slack ID or name
+        #[arg(short, long)]
+        member: Option<String>,
+        #[arg(short, long)]
+        content: Option<String>,
+        /// Blocker; repeat for several
+        #[arg(short, long)]
+        blocker: Vec<String>,
+        /// Achievement; repeat for several
+        #[arg(short, long)]
+        achievement: Vec<String>,
+        #[arg(long)]
+        mood: Option<String>,
+        /// Read the update as JSON or `key: value` lines from a file, or "-" for stdin
+        #[arg(short, long)]
+        from_file: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ProjectAction {
+    /// Create a new active project
+    Create {
+        #[arg(short, long)]
+        name: String,
+        #[arg(short, long, default_value = "")]
+        description: String,
+    },
+    /// List projects
+    List {
+        /// Only show projects with this status
+        #[arg(short, long)]
+        status: Option<String>,
+        /// Include archived projects
+        #[arg(short, long)]
+        all: bool,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Show a project and its status history
+    Show {
+        /// Project id or name
+        project: String,
+        /// Output format: table or json
+        #[arg(short, long, default_value = "table")]
+        format: String,
+    },
+    /// Change a project's status (active, on-hold, completed, cancelled)
+    SetStatus {
+        /// Project id or name
+        project: String,
+        status: String,
+        /// Allow transitions out of completed or cancelled
+        #[arg(long)]
+        force: bool,
+        /// Reason recorded in the status history
+        #[arg(short, long)]
+        note: Option<String>,
+    },
+    /// Archive a completed or cancelled project
+    Archive {
+        /// Project id or name
+        project: String,
+        /// Archive even if the project is still open
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
     let cli = Cli::parse();
     
+    if let Err(err) = run(cli).await {
+        error::report(&err);
+        std::process::exit(error::exit_code(&err));
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    // Load configuration
+    let overrides = ConfigOverrides {
+        data_dir: cli.data_dir,
+        log_level: cli.log_level.or_else(|| cli.verbose.then(|| "debug".to_string())),
+    };
+    let config = timeless::config::load_config(&cli.config, &overrides)?;
+    
     // Initialize logging
-    env_logger::init();
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or(&config.app.log_level)).init();
     
-    // Load configuration
-    let _config = timeless::config::load_config(&cli.config)?;
+    // Build the shared application context
+    let ctx = AppContext::new(config).await?;
     
     // Execute command
     match cli.command {
         Commands::Init { team_name, slack_channel } => {
-            timeless::cli::commands::init(team_name, slack_channel).await
+            timeless::cli::commands::init(&ctx, team_name, slack_channel).await
+        },
+        Commands::AddUser { name, email, role, slack_id } => {
+            timeless::cli::commands::add_user(&ctx, name, email, role, slack_id).await
+        },
+        Commands::Member { action } => match action {
+            MemberAction::List { role, format } => {
+                timeless::cli::member::list(&ctx, role, format).await
+            },
+            MemberAction::Show { member, format } => {
+                timeless::cli::member::show(&ctx, member, format).await
+            },
+            MemberAction::Edit { member, name, email, role, slack_id } => {
+                timeless::cli::member::edit(&ctx, member, name, email, role, slack_id).await
+            },
+            MemberAction::Remove { member } => {
+                timeless::cli::member::remove(&ctx, member).await
+            },
+        },
+        Commands::Project { action } => match action {
+            ProjectAction::Create { name, description } => {
+                timeless::cli::project::create(&ctx, name, description).await
+            },
+            ProjectAction::List { status, all, format } => {
+                timeless::cli::project::list(&ctx, status, all, format).await
+            },
+            ProjectAction::Show { project, format } => {
+                timeless::cli::project::show(&ctx, project, format).await
+            },
+            ProjectAction::SetStatus { project, status, force, note } => {
+                timeless::cli::project::set_status(&ctx, project, status, force, note).await
+            },
+            ProjectAction::Archive { project, force } => {
+                timeless::cli::project::archive(&ctx, project, force).await
+            },
+        },
+        Commands::Assign { member, project, allocation, start, end } => {
+            timeless::cli::project::assign(&ctx, member, project, allocation, start, end).await
+        },
+        Commands::Unassign { member, project } => {
+            timeless::cli::project::unassign(&ctx, member, project).await
         },
-        Commands::AddUser { name, email, slack_id } => {
-            timeless::cli::commands::add_user(name, email, slack_id).await
+        Commands::Status { action, mode, member, notify_slack, channel } => match action {
+            Some(StatusAction::Submit { member, content, blocker, achievement, mood, from_file }) => {
+                timeless::cli::status::submit(&ctx, member, content, blocker, achievement, mood, from_file).await
+            },
+            None => {
+                timeless::cli::status::collect(&ctx, mode, member, notify_slack, channel).await
+            },
         },
-        Commands::Status { mode, notify_slack } => {
-            timeless::cli::commands::status(mode, notify_slack).await
+        Commands::Report { report_type, output, format, ai_summary } => {
+            timeless::cli::report::generate(&ctx, report_type, output, format, ai_summary).await
         },
-        Commands::Report { report_type, output } => {
-            timeless::cli::commands::report(report_type, output).await
+        Commands::Blockers { action } => match action {
+            BlockersAction::List { open, older_than, member, format } => {
+                timeless::cli::blockers::list(&ctx, open, older_than, member, format).await
+            },
+            BlockersAction::Resolve { blocker } => {
+                timeless::cli::blockers::resolve(&ctx, blocker).await
+            },
+            BlockersAction::Edit { blocker, owner, severity, project, ticket } => {
+                timeless::cli::blockers::edit(&ctx, blocker, owner, severity, project, ticket).await
+            },
+            BlockersAction::Escalate { dry_run } => {
+                timeless::cli::blockers::escalate(&ctx, dry_run).await
+            },
+        },
+        Commands::Metrics { action } => match action {
+            MetricsAction::Compute { since, period, dry_run, format } => {
+                timeless::cli::metrics::compute(&ctx, since, period, dry_run, format).await
+            },
+            MetricsAction::Trends { weeks, window, format } => {
+                timeless::cli::metrics::trends(&ctx, weeks, window, format).await
+            },
+        },
+        Commands::Dashboard => {
+            timeless::cli::dashboard::run(&ctx).await
         },
         Commands::TestMcp => {
-            timeless::cli::commands::test_mcp().await
+            timeless::cli::commands::test_mcp(&ctx).await
         },
         Commands::Health => {
-            timeless::cli::commands::health().await
+            timeless::cli::commands::health(&ctx).await
+        },
+        Commands::Migrate { dry_run } => {
+            timeless::cli::commands::migrate(&ctx, dry_run).await
+        },
+        Commands::Export { output } => {
+            timeless::cli::commands::export(&ctx, output).await
+        },
+        Commands::Import { input, mode, dry_run } => {
+            timeless::cli::commands::import(&ctx, input, mode, dry_run).await
         },
-        Commands::QueryJira { projects, period } => {
-            timeless::cli::commands::query_jira(projects, period).await
+        Commands::QueryJira { projects, period, format } => {
+            timeless::cli::commands::query_jira(&ctx, projects, period, format).await
         },
         Commands::SlackMessage { channel, message } => {
-            timeless::cli::commands::send_slack_message(channel, message).await
+            timeless::cli::commands::send_slack_message(&ctx, channel, message).await
         },
         Commands::TeamStatus { channel, members } => {
-            timeless::cli::commands::collect_team_status(channel, members).await
+            timeless::cli::commands::collect_team_status(&ctx, channel, members).await
         },
     }
 }
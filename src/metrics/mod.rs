@@ -0,0 +1,111 @@
+use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+use crate::models::{Blocker, MetricsPeriod, StatusUpdate, TeamMetrics};
+use crate::storage::{Storage, TeamRepository};
+
+pub mod trends;
+
+/// Maps a free-text mood to a 1-10 satisfaction score. Numbers are taken
+/// as-is (clamped to 1-10); known words are mapped; anything else is `None`.
+pub fn mood_score(mood: &str) -> Option<f32> {
+    let mood = mood.trim().to_lowercase();
+    if let Ok(score) = mood.trim_end_matches("/10").trim().parse::<f32>() {
+        return score.is_finite().then(|| score.clamp(1.0, 10.0));
+    }
+    let score = match mood.as_str() {
+        "excellent" | "amazing" | "fantastic" | "awesome" => 10.0,
+        "great" | "happy" | "excited" => 9.0,
+        "good" | "productive" | "positive" => 8.0,
+        "fine" | "ok" | "okay" | "alright" => 6.0,
+        "meh" | "neutral" | "so-so" => 5.0,
+        "tired" | "busy" | "frustrated" => 4.0,
+        "stressed" | "anxious" | "overwhelmed" => 3.0,
+        "bad" | "unhappy" | "sad" => 2.0,
+        "terrible" | "awful" | "burned out" | "burnt out" => 1.0,
+        _ => return None,
+    };
+    Some(score)
+}
+
+/// Derives one `TeamMetrics` snapshot per period from status updates. Each
+/// snapshot is dated at the start of its period (midnight UTC) and counts:
+///
+/// - `active_members`: members who posted at least one update
+/// - `blockers_count`: distinct blockers as `Blocker::normalize` sees them,
+///   so one repeated daily or reported by several members counts once
+/// - `completed_tasks`: achievements reported
+/// - `average_satisfaction`: mean over members of each member's average mood
+///   score, or `None` when nobody gave a recognisable mood
+///
+/// Velocity can't be derived from updates and is left as `None`.
+pub fn aggregate(updates: &[StatusUpdate], period: MetricsPeriod) -> Vec<TeamMetrics> {
+    let mut buckets: BTreeMap<NaiveDate, Vec<&StatusUpdate>> = BTreeMap::new();
+    for update in updates {
+        buckets.entry(period.period_start(update.timestamp.date_naive())).or_default().push(update);
+    }
+
+    buckets
+        .into_iter()
+        .map(|(start, updates)| {
+            let mut metrics = TeamMetrics::new(start.and_hms_opt(0, 0, 0).unwrap().and_utc());
+            metrics.period = Some(period);
+
+            let active: HashSet<Uuid> = updates.iter().map(|u| u.member_id).collect();
+            let blockers: HashSet<String> = updates
+                .iter()
+                .flat_map(|u| u.blockers.iter().map(|b| Blocker::normalize(b)))
+                .collect();
+
+            let mut moods: HashMap<Uuid, Vec<f32>> = HashMap::new();
+            for update in &updates {
+                if let Some(score) = update.mood.as_deref().and_then(mood_score) {
+                    moods.entry(update.member_id).or_default().push(score);
+                }
+            }
+            let member_averages: Vec<f32> = moods.values().map(|s| s.iter().sum::<f32>() / s.len() as f32).collect();
+
+            metrics.active_members = active.len() as u32;
+            metrics.blockers_count = blockers.len() as u32;
+            metrics.completed_tasks = updates.iter().map(|u| u.achievements.len() as u32).sum();
+            if !member_averages.is_empty() {
+                metrics.average_satisfaction = Some(member_averages.iter().sum::<f32>() / member_averages.len() as f32);
+            }
+            metrics
+        })
+        .collect()
+}
+
+/// Aggregates the updates posted from `since` to now and saves the
+/// snapshots. A snapshot computed earlier for the same period is replaced in
+/// place, keeping its id and any velocity filled in since.
+pub fn compute<S: Storage>(
+    repo: &TeamRepository<S>,
+    since: DateTime<Utc>,
+    period: MetricsPeriod,
+    dry_run: bool,
+) -> Result<Vec<TeamMetrics>> {
+    // Start at the beginning of the first period so it is not undercounted
+    let start = period.period_start(since.date_naive()).and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let now = Utc::now();
+    let updates = repo.get_status_updates_in_range(start, now)?;
+    let mut snapshots = aggregate(&updates, period);
+
+    let existing: HashMap<DateTime<Utc>, TeamMetrics> = repo
+        .get_team_metrics_range(start, now)?
+        .into_iter()
+        .filter(|m| m.period == Some(period))
+        .map(|m| (m.date, m))
+        .collect();
+    for snapshot in &mut snapshots {
+        if let Some(previous) = existing.get(&snapshot.date) {
+            snapshot.id = previous.id;
+            snapshot.velocity = previous.velocity;
+        }
+        if !dry_run {
+            repo.save_team_metrics(snapshot)?;
+        }
+    }
+    Ok(snapshots)
+}
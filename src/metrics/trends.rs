@@ -0,0 +1,216 @@
+use chrono::{Duration, NaiveDate};
+use serde::Serialize;
+use std::fmt;
+use crate::models::{MetricsPeriod, TeamMetrics};
+
+/// How many standard deviations below the preceding weeks a value has to
+/// fall before it is flagged.
+pub const ANOMALY_Z_SCORE: f32 = 2.0;
+
+/// Preceding weeks with data needed before a week can be flagged.
+pub const MIN_ANOMALY_HISTORY: usize = 3;
+
+const SPARK_BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Series {
+    Velocity,
+    Satisfaction,
+    BlockersPerMember,
+}
+
+impl Series {
+    pub const ALL: [Series; 3] = [Series::Velocity, Series::Satisfaction, Series::BlockersPerMember];
+
+    /// Whether a fall in this series is bad news. Blockers per member is
+    /// the other way round: a spike is the unusual "drop" in health.
+    pub fn higher_is_better(self) -> bool {
+        !matches!(self, Series::BlockersPerMember)
+    }
+
+    fn value(self, week: &WeekBucket) -> Option<f32> {
+        match self {
+            Series::Velocity => week.velocity,
+            Series::Satisfaction => week.satisfaction,
+            Series::BlockersPerMember => week.blockers_per_member,
+        }
+    }
+}
+
+impl fmt::Display for Series {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Series::Velocity => "velocity",
+            Series::Satisfaction => "satisfaction",
+            Series::BlockersPerMember => "blockers/member",
+        })
+    }
+}
+
+/// The metrics for one ISO week, averaged over its snapshots. Weeks with no
+/// snapshots have no values.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WeekBucket {
+    pub week_start: NaiveDate,
+    pub snapshots: usize,
+    pub velocity: Option<f32>,
+    pub satisfaction: Option<f32>,
+    pub blockers_per_member: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SeriesTrend {
+    pub series: Series,
+    /// One entry per week, oldest first.
+    pub values: Vec<Option<f32>>,
+    /// Trailing mean over the last `window` weeks that have data.
+    pub moving_average: Vec<Option<f32>>,
+    /// Change from the previous week, when both weeks have data.
+    pub week_over_week: Vec<Option<f32>>,
+    /// Indexes of weeks that moved unusually far in the bad direction.
+    pub anomalies: Vec<usize>,
+}
+
+impl SeriesTrend {
+    pub fn latest(&self) -> Option<f32> {
+        self.values.last().copied().flatten()
+    }
+
+    pub fn sparkline(&self) -> String {
+        sparkline(&self.values)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Trends {
+    pub weeks: Vec<WeekBucket>,
+    pub series: Vec<SeriesTrend>,
+}
+
+/// Groups `metrics` into the `weeks` ISO weeks ending with the one that
+/// contains `last_day`. A week with weekly snapshots uses those; otherwise
+/// its daily and hand-recorded snapshots are averaged.
+pub fn weekly_buckets(metrics: &[TeamMetrics], last_day: NaiveDate, weeks: usize) -> Vec<WeekBucket> {
+    let last_week = MetricsPeriod::Weekly.period_start(last_day);
+    let mut sorted: Vec<&TeamMetrics> = metrics.iter().collect();
+    sorted.sort_by_key(|m| m.date);
+
+    (0..weeks)
+        .rev()
+        .map(|ago| {
+            let week_start = last_week - Duration::weeks(ago as i64);
+            let in_week: Vec<&TeamMetrics> = sorted
+                .iter()
+                .copied()
+                .filter(|m| MetricsPeriod::Weekly.period_start(m.date.date_naive()) == week_start)
+                .collect();
+            let weekly: Vec<&TeamMetrics> = in_week.iter().copied().filter(|m| m.period == Some(MetricsPeriod::Weekly)).collect();
+            let chosen = if weekly.is_empty() { in_week } else { weekly };
+
+            let with_members: Vec<f32> = chosen.iter().filter(|m| m.active_members > 0).map(|m| m.blocker_ratio()).collect();
+            WeekBucket {
+                week_start,
+                snapshots: chosen.len(),
+                velocity: mean(chosen.iter().filter_map(|m| m.velocity)),
+                satisfaction: mean(chosen.iter().filter_map(|m| m.average_satisfaction)),
+                blockers_per_member: mean(with_members),
+            }
+        })
+        .collect()
+}
+
+/// Weekly trends for the `weeks` weeks ending with the one containing
+/// `last_day`, with moving averages over `window` weeks.
+pub fn analyze(metrics: &[TeamMetrics], last_day: NaiveDate, weeks: usize, window: usize) -> Trends {
+    let buckets = weekly_buckets(metrics, last_day, weeks);
+    let series = Series::ALL
+        .iter()
+        .map(|&series| {
+            let values: Vec<Option<f32>> = buckets.iter().map(|week| series.value(week)).collect();
+            SeriesTrend {
+                series,
+                moving_average: moving_average(&values, window),
+                week_over_week: week_over_week(&values),
+                anomalies: anomalies(&values, series.higher_is_better()),
+                values,
+            }
+        })
+        .collect();
+    Trends { weeks: buckets, series }
+}
+
+/// Trailing mean of up to `window` of the most recent values that exist.
+pub fn moving_average(values: &[Option<f32>], window: usize) -> Vec<Option<f32>> {
+    let window = window.max(1);
+    (0..values.len())
+        .map(|i| {
+            values[i]?;
+            mean(values[..=i].iter().rev().flatten().take(window).copied())
+        })
+        .collect()
+}
+
+pub fn week_over_week(values: &[Option<f32>]) -> Vec<Option<f32>> {
+    (0..values.len())
+        .map(|i| match (i.checked_sub(1).and_then(|p| values[p]), values[i]) {
+            (Some(previous), Some(current)) => Some(current - previous),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Weeks whose value sits more than `ANOMALY_Z_SCORE` standard deviations
+/// from the mean of the weeks before it, in the bad direction.
+pub fn anomalies(values: &[Option<f32>], higher_is_better: bool) -> Vec<usize> {
+    (0..values.len())
+        .filter(|&i| {
+            let Some(value) = values[i] else {
+                return false;
+            };
+            let history: Vec<f32> = values[..i].iter().flatten().copied().collect();
+            if history.len() < MIN_ANOMALY_HISTORY {
+                return false;
+            }
+            let mean = history.iter().sum::<f32>() / history.len() as f32;
+            let variance = history.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / (history.len() - 1) as f32;
+            // A perfectly steady history would make any wobble infinitely
+            // unusual, so the deviation is floored at 5% of the mean
+            let deviation = variance.sqrt().max(mean.abs() * 0.05);
+            if deviation < f32::EPSILON {
+                return false;
+            }
+            let z = (value - mean) / deviation;
+            if higher_is_better { z < -ANOMALY_Z_SCORE } else { z > ANOMALY_Z_SCORE }
+        })
+        .collect()
+}
+
+/// Renders values as a row of block characters scaled between their minimum
+/// and maximum. Missing values are left blank.
+pub fn sparkline(values: &[Option<f32>]) -> String {
+    let present: Vec<f32> = values.iter().flatten().copied().collect();
+    let (Some(min), Some(max)) = (
+        present.iter().copied().reduce(f32::min),
+        present.iter().copied().reduce(f32::max),
+    ) else {
+        return " ".repeat(values.len());
+    };
+
+    values
+        .iter()
+        .map(|value| match value {
+            None => ' ',
+            Some(_) if (max - min).abs() < f32::EPSILON => SPARK_BARS[SPARK_BARS.len() / 2],
+            Some(v) => {
+                let level = ((v - min) / (max - min) * (SPARK_BARS.len() - 1) as f32).round() as usize;
+                SPARK_BARS[level.min(SPARK_BARS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
+fn mean(values: impl IntoIterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = values.into_iter().fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
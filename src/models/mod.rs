@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use anyhow::{anyhow, bail, Result};
+use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamMember {
@@ -36,6 +39,84 @@ impl TeamMember {
     pub fn update(&mut self) {
         self.updated_at = Utc::now();
     }
+    
+    /// Checks the fields that do not depend on other members.
+    pub fn validate(&self) -> std::result::Result<(), MemberError> {
+        if self.name.trim().is_empty() {
+            return Err(MemberError::EmptyName);
+        }
+        if !is_valid_email(&self.email) {
+            return Err(MemberError::InvalidEmail(self.email.clone()));
+        }
+        if let Some(slack_id) = &self.slack_id {
+            if !is_valid_slack_id(slack_id) {
+                return Err(MemberError::InvalidSlackId(slack_id.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Rejects an email or Slack ID already used by one of `others`. The
+    /// member itself may be among them.
+    pub fn check_unique<'a>(&self, others: impl IntoIterator<Item = &'a TeamMember>) -> std::result::Result<(), MemberError> {
+        for other in others.into_iter().filter(|m| m.id != self.id) {
+            if other.email.eq_ignore_ascii_case(&self.email) {
+                return Err(MemberError::DuplicateEmail {
+                    email: self.email.clone(),
+                    id: other.id,
+                    name: other.name.clone(),
+                });
+            }
+            if let (Some(slack_id), Some(existing)) = (&self.slack_id, &other.slack_id) {
+                if slack_id.eq_ignore_ascii_case(existing) {
+                    return Err(MemberError::DuplicateSlackId {
+                        slack_id: slack_id.clone(),
+                        id: other.id,
+                        name: other.name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MemberError {
+    #[error("member name must not be empty")]
+    EmptyName,
+    #[error("{0:?} is not a valid email address")]
+    InvalidEmail(String),
+    #[error("{0:?} is not a valid Slack ID; expected a member ID like U012AB3CD or an @handle")]
+    InvalidSlackId(String),
+    #[error("email {email} is already used by {name} ({id})")]
+    DuplicateEmail { email: String, id: Uuid, name: String },
+    #[error("Slack ID {slack_id} is already used by {name} ({id})")]
+    DuplicateSlackId { slack_id: String, id: Uuid, name: String },
+}
+
+/// A single `local@domain.tld` address without whitespace.
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !email.chars().any(char::is_whitespace)
+        && !domain.contains('@')
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+/// Slack member IDs (`U…`/`W…` followed by upper-case alphanumerics) or
+/// display handles such as `@john.doe`.
+fn is_valid_slack_id(slack_id: &str) -> bool {
+    if let Some(handle) = slack_id.strip_prefix('@') {
+        return !handle.is_empty()
+            && handle.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+    }
+    slack_id.len() >= 7
+        && slack_id.starts_with(['U', 'W'])
+        && slack_id.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,6 +127,8 @@ pub struct Project {
     pub status: ProjectStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub status_history: Vec<StatusChange>,
+    pub archived_at: Option<DateTime<Utc>>,
 }
 
 impl Project {
@@ -58,12 +141,55 @@ impl Project {
             status: ProjectStatus::Active,
             created_at: now,
             updated_at: now,
+            status_history: Vec::new(),
+            archived_at: None,
         }
     }
     
+    /// Changes the status without checking whether the transition is allowed.
+    /// The change is still recorded in the history.
     pub fn set_status(&mut self, status: ProjectStatus) {
+        let forced = !self.status.can_transition_to(status);
+        self.record_status_change(status, forced, None);
+    }
+    
+    /// Changes the status if `ProjectStatus::can_transition_to` allows it, or
+    /// unconditionally when `force` is set.
+    pub fn transition_to(&mut self, status: ProjectStatus, force: bool, note: Option<String>) -> std::result::Result<(), ProjectError> {
+        if self.status == status {
+            return Err(ProjectError::AlreadyInStatus { name: self.name.clone(), status });
+        }
+        let allowed = self.status.can_transition_to(status);
+        if !allowed && !force {
+            return Err(ProjectError::TransitionNotAllowed { name: self.name.clone(), from: self.status, to: status });
+        }
+        
+        self.record_status_change(status, !allowed, note);
+        Ok(())
+    }
+    
+    /// Appends to the history; `forced` marks a change that bypassed the rules.
+    fn record_status_change(&mut self, status: ProjectStatus, forced: bool, note: Option<String>) {
+        let now = Utc::now();
+        self.status_history.push(StatusChange {
+            from: self.status,
+            to: status,
+            changed_at: now,
+            forced,
+            note,
+        });
         self.status = status;
-        self.updated_at = Utc::now();
+        self.updated_at = now;
+    }
+    
+    pub fn archive(&mut self) {
+        let now = Utc::now();
+        self.archived_at = Some(now);
+        self.updated_at = now;
+    }
+    
+    pub fn is_archived(&self) -> bool {
+        self.archived_at.is_some()
     }
     
     pub fn is_active(&self) -> bool {
@@ -71,7 +197,7 @@ impl Project {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProjectStatus {
     Active,
     Completed,
@@ -79,6 +205,120 @@ pub enum ProjectStatus {
     Cancelled,
 }
 
+impl ProjectStatus {
+    /// Whether moving to `next` is a normal transition. Completed and
+    /// cancelled projects are closed; reopening them needs to be forced.
+    pub fn can_transition_to(self, next: ProjectStatus) -> bool {
+        use ProjectStatus::*;
+        matches!(
+            (self, next),
+            (Active, OnHold | Completed | Cancelled) | (OnHold, Active | Completed | Cancelled)
+        )
+    }
+    
+    pub fn is_closed(self) -> bool {
+        matches!(self, ProjectStatus::Completed | ProjectStatus::Cancelled)
+    }
+}
+
+impl fmt::Display for ProjectStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ProjectStatus::Active => "active",
+            ProjectStatus::Completed => "completed",
+            ProjectStatus::OnHold => "on-hold",
+            ProjectStatus::Cancelled => "cancelled",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ProjectStatus {
+    type Err = anyhow::Error;
+    
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().replace(['_', ' '], "-").as_str() {
+            "active" => Ok(ProjectStatus::Active),
+            "completed" | "done" => Ok(ProjectStatus::Completed),
+            "on-hold" | "onhold" => Ok(ProjectStatus::OnHold),
+            "cancelled" | "canceled" => Ok(ProjectStatus::Cancelled),
+            other => Err(anyhow!(
+                "unknown project status {:?}, expected active, completed, on-hold or cancelled",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ProjectError {
+    #[error("project {name} is already {status}")]
+    AlreadyInStatus { name: String, status: ProjectStatus },
+    #[error("cannot move project {name} from {from} to {to}")]
+    TransitionNotAllowed { name: String, from: ProjectStatus, to: ProjectStatus },
+}
+
+/// One entry in a project's status history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub from: ProjectStatus,
+    pub to: ProjectStatus,
+    pub changed_at: DateTime<Utc>,
+    /// Set when the transition bypassed the normal rules.
+    pub forced: bool,
+    pub note: Option<String>,
+}
+
+/// A member working on a project for part of their time over a date range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub project_id: Uuid,
+    /// Share of the member's time, in percent.
+    pub allocation: u8,
+    pub start_date: NaiveDate,
+    /// Last day of the assignment; open-ended when unset.
+    pub end_date: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Assignment {
+    pub fn new(member_id: Uuid, project_id: Uuid, allocation: u8, start_date: NaiveDate) -> Self {
+        let now = Utc::now();
+        Assignment {
+            id: Uuid::new_v4(),
+            member_id,
+            project_id,
+            allocation,
+            start_date,
+            end_date: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+    
+    pub fn with_end_date(mut self, end_date: NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+    
+    pub fn validate(&self) -> Result<()> {
+        if !(1..=100).contains(&self.allocation) {
+            bail!("allocation must be between 1 and 100 percent, got {}", self.allocation);
+        }
+        if self.end_date.is_some_and(|end| end < self.start_date) {
+            bail!("assignment ends before it starts");
+        }
+        Ok(())
+    }
+    
+    pub fn is_active_on(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && self.end_date.is_none_or(|end| date <= end)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StatusUpdate {
     pub id: Uuid,
@@ -121,6 +361,185 @@ impl StatusUpdate {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockerSeverity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+impl fmt::Display for BlockerSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BlockerSeverity::Low => "low",
+            BlockerSeverity::Medium => "medium",
+            BlockerSeverity::High => "high",
+            BlockerSeverity::Critical => "critical",
+        })
+    }
+}
+
+impl FromStr for BlockerSeverity {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Ok(BlockerSeverity::Low),
+            "medium" | "med" => Ok(BlockerSeverity::Medium),
+            "high" => Ok(BlockerSeverity::High),
+            "critical" | "crit" => Ok(BlockerSeverity::Critical),
+            other => Err(anyhow!("unknown severity {:?}, expected low, medium, high or critical", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockerStatus {
+    Open,
+    Resolved,
+}
+
+impl fmt::Display for BlockerStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BlockerStatus::Open => "open",
+            BlockerStatus::Resolved => "resolved",
+        })
+    }
+}
+
+/// A blocker tracked from the status update that first reported it until it
+/// is resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blocker {
+    pub id: Uuid,
+    pub description: String,
+    pub reporter_id: Uuid,
+    /// Other members whose updates reported the same blocker while it was open.
+    #[serde(default)]
+    pub also_reported_by: Vec<Uuid>,
+    pub owner_id: Option<Uuid>,
+    pub project_id: Option<Uuid>,
+    pub ticket: Option<String>,
+    pub severity: BlockerSeverity,
+    pub status: BlockerStatus,
+    pub opened_at: DateTime<Utc>,
+    /// When a status update last mentioned it.
+    pub last_reported_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+}
+
+impl Blocker {
+    /// Builds a blocker from the text of a status update. A leading
+    /// `[high]`-style tag sets the severity and a Jira-style key such as
+    /// `PROJ-123` in the text becomes the linked ticket.
+    pub fn from_report(text: &str, reporter_id: Uuid, reported_at: DateTime<Utc>) -> Self {
+        let (severity, description) = split_severity_tag(text);
+        Blocker {
+            id: Uuid::new_v4(),
+            ticket: find_ticket_key(description),
+            description: description.to_string(),
+            reporter_id,
+            also_reported_by: Vec::new(),
+            owner_id: None,
+            project_id: None,
+            severity: severity.unwrap_or(BlockerSeverity::Medium),
+            status: BlockerStatus::Open,
+            opened_at: reported_at,
+            last_reported_at: reported_at,
+            resolved_at: None,
+        }
+    }
+
+    /// Case- and whitespace-insensitive form of a reported blocker, used to
+    /// recognise the same blocker across updates and members.
+    pub fn normalize(text: &str) -> String {
+        let (_, description) = split_severity_tag(text);
+        description.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+    }
+
+    pub fn matches(&self, text: &str) -> bool {
+        Blocker::normalize(&self.description) == Blocker::normalize(text)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == BlockerStatus::Open
+    }
+
+    /// Everyone who reported it, reporter first.
+    pub fn reporters(&self) -> impl Iterator<Item = Uuid> + '_ {
+        std::iter::once(self.reporter_id).chain(self.also_reported_by.iter().copied())
+    }
+
+    pub fn is_reported_by(&self, member_id: Uuid) -> bool {
+        self.reporters().any(|id| id == member_id)
+    }
+
+    /// Time open so far, or until it was resolved.
+    pub fn age(&self, now: DateTime<Utc>) -> chrono::Duration {
+        self.resolved_at.unwrap_or(now) - self.opened_at
+    }
+
+    pub fn resolve(&mut self, at: DateTime<Utc>) {
+        self.status = BlockerStatus::Resolved;
+        self.resolved_at = Some(at);
+    }
+}
+
+fn split_severity_tag(text: &str) -> (Option<BlockerSeverity>, &str) {
+    let text = text.trim();
+    if let Some(rest) = text.strip_prefix('[') {
+        if let Some((tag, description)) = rest.split_once(']') {
+            if let Ok(severity) = tag.parse() {
+                return (Some(severity), description.trim());
+            }
+        }
+    }
+    (None, text)
+}
+
+/// Whether `key` looks like a Jira issue key: a project key of at least two
+/// upper-case letters, digits or underscores starting with a letter, a dash
+/// and a number.
+pub fn is_ticket_key(key: &str) -> bool {
+    let Some((project, number)) = key.split_once('-') else {
+        return false;
+    };
+    project.len() >= 2
+        && project.starts_with(|c: char| c.is_ascii_uppercase())
+        && project.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// The first word in `text` that `is_ticket_key`.
+fn find_ticket_key(text: &str) -> Option<String> {
+    text.split(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        .find(|word| is_ticket_key(word))
+        .map(str::to_string)
+}
+
+/// A blocker sent to one target by an escalation rule, kept so the same
+/// escalation is not sent again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Escalation {
+    pub id: Uuid,
+    pub blocker_id: Uuid,
+    pub rule: String,
+    /// Where it went, e.g. `slack:#engineering` or `email:lead@company.com`.
+    pub target: String,
+    pub sent_at: DateTime<Utc>,
+}
+
+impl Escalation {
+    pub fn new(blocker_id: Uuid, rule: String, target: String, sent_at: DateTime<Utc>) -> Self {
+        Escalation { id: Uuid::new_v4(), blocker_id, rule, target, sent_at }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Conversation {
     pub id: Uuid,
@@ -178,6 +597,39 @@ impl AIDecision {
     }
 }
 
+/// How `TeamMetrics` are scored and when they count as concerning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Relative weights of the health score components. They are normalised
+    /// by their sum, so the score stays out of 10.
+    pub satisfaction_weight: f32,
+    pub velocity_weight: f32,
+    pub blocker_weight: f32,
+    /// Velocity that scores full marks; higher values are capped.
+    pub velocity_cap: f32,
+    /// Satisfaction below this is a concern; 0 disables the check.
+    pub min_satisfaction: f32,
+    /// Velocity below this is a concern; 0 disables the check.
+    pub min_velocity: f32,
+    /// Blockers per active member above this are a concern.
+    pub max_blocker_ratio: f32,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        MetricsConfig {
+            satisfaction_weight: 0.4,
+            velocity_weight: 0.3,
+            blocker_weight: 0.3,
+            velocity_cap: 100.0,
+            min_satisfaction: 5.0,
+            min_velocity: 10.0,
+            max_blocker_ratio: 0.5,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamMetrics {
     pub id: Uuid,
@@ -185,8 +637,55 @@ pub struct TeamMetrics {
     pub active_members: u32,
     pub completed_tasks: u32,
     pub blockers_count: u32,
-    pub average_satisfaction: f32,
-    pub velocity: f32,
+    /// `None` when nobody gave a mood that could be scored.
+    #[serde(default)]
+    pub average_satisfaction: Option<f32>,
+    /// `None` until filled in; it can't be derived from status updates.
+    #[serde(default)]
+    pub velocity: Option<f32>,
+    /// Set on snapshots computed from status updates; `None` when recorded by hand.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub period: Option<MetricsPeriod>,
+}
+
+/// The span a computed `TeamMetrics` snapshot covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsPeriod {
+    Daily,
+    /// ISO weeks, starting on Monday.
+    Weekly,
+}
+
+impl MetricsPeriod {
+    /// First day of the period that contains `date`.
+    pub fn period_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            MetricsPeriod::Daily => date,
+            MetricsPeriod::Weekly => date - Duration::days(date.weekday().num_days_from_monday() as i64),
+        }
+    }
+}
+
+impl fmt::Display for MetricsPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MetricsPeriod::Daily => "daily",
+            MetricsPeriod::Weekly => "weekly",
+        })
+    }
+}
+
+impl FromStr for MetricsPeriod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "daily" | "day" => Ok(MetricsPeriod::Daily),
+            "weekly" | "week" => Ok(MetricsPeriod::Weekly),
+            other => Err(anyhow!("unknown period {:?}, expected daily or weekly", other)),
+        }
+    }
 }
 
 impl TeamMetrics {
@@ -197,32 +696,81 @@ impl TeamMetrics {
             active_members: 0,
             completed_tasks: 0,
             blockers_count: 0,
-            average_satisfaction: 0.0,
-            velocity: 0.0,
+            average_satisfaction: None,
+            velocity: None,
+            period: None,
         }
     }
     
     pub fn calculate_health_score(&self) -> f32 {
-        let satisfaction_weight = 0.4;
-        let velocity_weight = 0.3;
-        let blocker_penalty = 0.3;
-        
-        let satisfaction_score = self.average_satisfaction / 10.0; // Normalize to 0-1
-        let velocity_score = (self.velocity / 100.0).min(1.0); // Normalize to 0-1, cap at 1
-        let blocker_score = if self.active_members > 0 {
-            1.0 - (self.blockers_count as f32 / self.active_members as f32).min(1.0)
+        self.health_score_with(&MetricsConfig::default())
+    }
+
+    /// Health out of 10, weighting satisfaction, velocity against
+    /// `velocity_cap` and the share of active members who are not blocked.
+    /// Unknown satisfaction or velocity is left out rather than scored as 0.
+    pub fn health_score_with(&self, config: &MetricsConfig) -> f32 {
+        let components = [
+            self.average_satisfaction.map(|s| ((s / 10.0).clamp(0.0, 1.0), config.satisfaction_weight)),
+            self.velocity.map(|v| ((v / config.velocity_cap).clamp(0.0, 1.0), config.velocity_weight)),
+            Some((1.0 - self.blocker_ratio().min(1.0), config.blocker_weight)),
+        ];
+
+        let total_weight: f32 = components.iter().flatten().map(|(_, weight)| weight).sum();
+        if total_weight <= 0.0 {
+            return 0.0;
+        }
+        components.iter().flatten().map(|(score, weight)| score * weight).sum::<f32>() / total_weight * 10.0
+    }
+
+    /// Blockers per active member, or 0 when nobody was active.
+    pub fn blocker_ratio(&self) -> f32 {
+        if self.active_members > 0 {
+            self.blockers_count as f32 / self.active_members as f32
         } else {
-            1.0
-        };
-        
-        (satisfaction_score * satisfaction_weight 
-         + velocity_score * velocity_weight 
-         + blocker_score * blocker_penalty) * 10.0
+            0.0
+        }
     }
-    
+
     pub fn has_concerning_metrics(&self) -> bool {
-        self.average_satisfaction < 5.0 
-            || (self.active_members > 0 && (self.blockers_count as f32 / self.active_members as f32) > 0.5)
-            || self.velocity < 10.0
+        !self.concerns(&MetricsConfig::default()).is_empty()
     }
-}
\ No newline at end of file
+
+    /// Every threshold in `config` these metrics fall on the wrong side of.
+    pub fn concerns(&self, config: &MetricsConfig) -> Vec<Concern> {
+        let mut concerns = Vec::new();
+        if let Some(satisfaction) = self.average_satisfaction.filter(|&s| s < config.min_satisfaction) {
+            concerns.push(Concern::LowSatisfaction { value: satisfaction, threshold: config.min_satisfaction });
+        }
+        if self.active_members > 0 && self.blocker_ratio() > config.max_blocker_ratio {
+            concerns.push(Concern::HighBlockerRatio { value: self.blocker_ratio(), threshold: config.max_blocker_ratio });
+        }
+        if let Some(velocity) = self.velocity.filter(|&v| v < config.min_velocity) {
+            concerns.push(Concern::LowVelocity { value: velocity, threshold: config.min_velocity });
+        }
+        concerns
+    }
+}
+
+/// Why a set of metrics counts as concerning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Concern {
+    LowSatisfaction { value: f32, threshold: f32 },
+    HighBlockerRatio { value: f32, threshold: f32 },
+    LowVelocity { value: f32, threshold: f32 },
+}
+
+impl fmt::Display for Concern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Concern::LowSatisfaction { value, threshold } => {
+                write!(f, "satisfaction {:.1}/10 is below {:.1}", value, threshold)
+            }
+            Concern::HighBlockerRatio { value, threshold } => {
+                write!(f, "{:.2} blockers per active member is above {:.2}", value, threshold)
+            }
+            Concern::LowVelocity { value, threshold } => write!(f, "velocity {:.1} is below {:.1}", value, threshold),
+        }
+    }
+}
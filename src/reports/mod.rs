@@ -0,0 +1,281 @@
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+use crate::claude::prompts::REPORT_GENERATION_PROMPT;
+use crate::config::MetricsConfig;
+use crate::models::{Concern, StatusUpdate, TeamMetrics};
+use crate::storage::{Storage, TeamRepository};
+
+pub mod render;
+
+pub use render::ReportFormat;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportType {
+    Daily,
+    Weekly,
+    Sprint,
+    Monthly,
+}
+
+impl ReportType {
+    /// Length of the window a report of this type covers.
+    pub fn duration(self) -> Duration {
+        match self {
+            ReportType::Daily => Duration::days(1),
+            ReportType::Weekly => Duration::weeks(1),
+            ReportType::Sprint => Duration::weeks(2),
+            ReportType::Monthly => Duration::days(30),
+        }
+    }
+}
+
+impl fmt::Display for ReportType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ReportType::Daily => "daily",
+            ReportType::Weekly => "weekly",
+            ReportType::Sprint => "sprint",
+            ReportType::Monthly => "monthly",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ReportType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "daily" => Ok(ReportType::Daily),
+            "weekly" => Ok(ReportType::Weekly),
+            "sprint" => Ok(ReportType::Sprint),
+            "monthly" => Ok(ReportType::Monthly),
+            other => Err(anyhow!("unknown report type {:?}, expected daily, weekly, sprint or monthly", other)),
+        }
+    }
+}
+
+/// Everything a report covers, gathered from the repository for one window.
+#[derive(Debug, Clone, Serialize)]
+pub struct Report {
+    pub report_type: ReportType,
+    pub team_name: String,
+    pub period_start: DateTime<Utc>,
+    pub period_end: DateTime<Utc>,
+    pub generated_at: DateTime<Utc>,
+    pub members: Vec<MemberSection>,
+    pub blockers: Vec<ReportItem>,
+    pub achievements: Vec<ReportItem>,
+    pub projects: Vec<ProjectSummary>,
+    /// The latest metrics recorded in the window.
+    pub metrics: Option<TeamMetrics>,
+    /// Health score of `metrics` out of 10, under the configured weights.
+    pub health_score: Option<f32>,
+    /// Thresholds `metrics` crossed; empty when there are no metrics.
+    pub concerns: Vec<Concern>,
+    /// Narrative summary from Claude, when one was requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberSection {
+    pub member_id: Uuid,
+    pub name: String,
+    pub role: String,
+    pub projects: Vec<String>,
+    /// Oldest first; empty when the member posted nothing in the window.
+    pub updates: Vec<StatusUpdate>,
+}
+
+/// A blocker or achievement, attributed to the members who reported it.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportItem {
+    pub member: String,
+    pub text: String,
+    pub reported_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectSummary {
+    pub name: String,
+    pub status: String,
+    pub members: Vec<String>,
+}
+
+impl Report {
+    /// Collects the report for the window of `report_type` ending at `end`,
+    /// scoring metrics with `health`.
+    pub fn gather<S: Storage>(
+        repo: &TeamRepository<S>,
+        report_type: ReportType,
+        team_name: &str,
+        health: &MetricsConfig,
+        end: DateTime<Utc>,
+    ) -> Result<Self> {
+        let start = end - report_type.duration();
+        let updates = repo.get_status_updates_in_range(start, end)?;
+        let today = end.date_naive();
+
+        let mut team = repo.list_team_members()?;
+        team.sort_by_key(|m| m.name.to_lowercase());
+        let names: HashMap<Uuid, String> = team.iter().map(|m| (m.id, m.name.clone())).collect();
+
+        // Tracked blockers rather than the raw text of each update, so one
+        // reported by several members or on several days is listed once
+        let blockers: Vec<ReportItem> = repo.list_blockers()?
+            .into_iter()
+            .filter(|b| b.opened_at <= end && b.last_reported_at >= start)
+            .map(|b| ReportItem {
+                member: b.reporters()
+                    .map(|id| names.get(&id).cloned().unwrap_or_else(|| id.to_string()[..8].to_string()))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                text: b.description,
+                reported_at: b.opened_at,
+            })
+            .collect();
+
+        let mut members = Vec::new();
+        let mut achievements = Vec::new();
+        for member in team {
+            let member_updates: Vec<StatusUpdate> = updates.iter().filter(|u| u.member_id == member.id).cloned().collect();
+            for update in &member_updates {
+                achievements.extend(update.achievements.iter().map(|text| ReportItem {
+                    member: member.name.clone(),
+                    text: text.clone(),
+                    reported_at: update.timestamp,
+                }));
+            }
+            let projects = repo.get_projects_for_member(member.id, today)?
+                .into_iter()
+                .map(|(project, _)| project.name)
+                .collect();
+
+            members.push(MemberSection {
+                member_id: member.id,
+                name: member.name,
+                role: member.role,
+                projects,
+                updates: member_updates,
+            });
+        }
+
+        let mut projects = Vec::new();
+        let mut all_projects = repo.list_projects()?;
+        all_projects.retain(|p| !p.is_archived());
+        all_projects.sort_by_key(|p| p.name.to_lowercase());
+        for project in all_projects {
+            let members = repo.get_members_on_project(project.id, today)?
+                .into_iter()
+                .map(|(member, _)| member.name)
+                .collect();
+            projects.push(ProjectSummary { name: project.name, status: project.status.to_string(), members });
+        }
+
+        let metrics = repo.get_team_metrics_range(start, end)?
+            .into_iter()
+            .max_by_key(|m| m.date);
+        let health_score = metrics.as_ref().map(|m| m.health_score_with(health));
+        let concerns = metrics.as_ref().map(|m| m.concerns(health)).unwrap_or_default();
+
+        Ok(Report {
+            report_type,
+            team_name: team_name.to_string(),
+            period_start: start,
+            period_end: end,
+            generated_at: Utc::now(),
+            members,
+            blockers,
+            achievements,
+            projects,
+            metrics,
+            health_score,
+            concerns,
+            summary: None,
+        })
+    }
+
+    pub fn update_count(&self) -> usize {
+        self.members.iter().map(|m| m.updates.len()).sum()
+    }
+
+    /// Members with at least one update in the window.
+    pub fn reporting_members(&self) -> usize {
+        self.members.iter().filter(|m| !m.updates.is_empty()).count()
+    }
+
+    pub fn time_period(&self) -> String {
+        format!("{} to {}", self.period_start.format("%Y-%m-%d %H:%M UTC"), self.period_end.format("%Y-%m-%d %H:%M UTC"))
+    }
+
+    /// Plain-text listing of the team data, for use inside prompts.
+    pub fn team_data(&self) -> String {
+        let mut lines = vec![format!("Team: {}", self.team_name)];
+
+        for member in &self.members {
+            let projects = if member.projects.is_empty() {
+                String::new()
+            } else {
+                format!(", working on {}", member.projects.join(", "))
+            };
+            lines.push(format!("\n{} ({}{})", member.name, member.role, projects));
+            if member.updates.is_empty() {
+                lines.push("  No status updates in this period".to_string());
+            }
+            for update in &member.updates {
+                let mood = update.mood.as_deref().map(|m| format!(" [mood: {}]", m)).unwrap_or_default();
+                lines.push(format!("  {}: {}{}", update.timestamp.format("%Y-%m-%d"), update.content, mood));
+                for blocker in &update.blockers {
+                    lines.push(format!("    Blocker: {}", blocker));
+                }
+                for achievement in &update.achievements {
+                    lines.push(format!("    Achievement: {}", achievement));
+                }
+            }
+        }
+
+        if !self.projects.is_empty() {
+            lines.push("\nProjects:".to_string());
+            for project in &self.projects {
+                let members = if project.members.is_empty() { "nobody assigned".to_string() } else { project.members.join(", ") };
+                lines.push(format!("  {} ({}): {}", project.name, project.status, members));
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Plain-text metrics summary, for use inside prompts.
+    pub fn metrics_summary(&self) -> String {
+        let mut lines = vec![
+            format!("Status updates: {}", self.update_count()),
+            format!("Blockers reported: {}", self.blockers.len()),
+            format!("Achievements reported: {}", self.achievements.len()),
+        ];
+        if let Some(metrics) = &self.metrics {
+            let satisfaction = metrics.average_satisfaction.map_or_else(|| "unknown".to_string(), |s| format!("{:.1}/10", s));
+            let velocity = metrics.velocity.map_or_else(|| "unknown".to_string(), |v| format!("{:.1}", v));
+            lines.push(format!(
+                "Active members: {}, completed tasks: {}, average satisfaction: {}, velocity: {}",
+                metrics.active_members, metrics.completed_tasks, satisfaction, velocity
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// The report request rendered through `REPORT_GENERATION_PROMPT`.
+    pub fn prompt(&self) -> String {
+        render::fill(REPORT_GENERATION_PROMPT, &[
+            ("report_type", &self.report_type.to_string()),
+            ("time_period", &self.time_period()),
+            ("team_data", &self.team_data()),
+            ("metrics", &self.metrics_summary()),
+        ])
+    }
+}
@@ -0,0 +1,326 @@
+use anyhow::{anyhow, Result};
+use std::fmt;
+use std::str::FromStr;
+use crate::reports::{Report, ReportItem};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+            ReportFormat::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ReportFormat::Markdown => "markdown",
+            ReportFormat::Html => "html",
+            ReportFormat::Json => "json",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            "json" => Ok(ReportFormat::Json),
+            other => Err(anyhow!("unknown report format {:?}, expected markdown, html or json", other)),
+        }
+    }
+}
+
+pub const MARKDOWN_TEMPLATE: &str = r#"# {title}
+
+_{time_period}, generated {generated_at}_
+
+{summary}## Metrics
+
+{metrics}
+
+## Blockers
+
+{blockers}
+
+## Achievements
+
+{achievements}
+
+## Team Updates
+
+{members}
+
+## Projects
+
+{projects}
+"#;
+
+pub const HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>
+body { font-family: -apple-system, "Segoe UI", Helvetica, Arial, sans-serif; max-width: 860px; margin: 2rem auto; padding: 0 1rem; color: #222; line-height: 1.5; }
+h1 { margin-bottom: 0; }
+h2 { border-bottom: 1px solid #ddd; padding-bottom: .25rem; margin-top: 2rem; }
+.period, .empty { color: #666; }
+table { border-collapse: collapse; }
+td, th { text-align: left; padding: .25rem 1rem .25rem 0; }
+.concern { color: #b00020; font-weight: bold; }
+.member { margin-bottom: 1rem; }
+.blocker { color: #b00020; }
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p class="period">{time_period}, generated {generated_at}</p>
+{summary}<h2>Metrics</h2>
+{metrics}
+<h2>Blockers</h2>
+{blockers}
+<h2>Achievements</h2>
+{achievements}
+<h2>Team Updates</h2>
+{members}
+<h2>Projects</h2>
+{projects}
+</body>
+</html>
+"#;
+
+impl Report {
+    pub fn title(&self) -> String {
+        let kind = self.report_type.to_string();
+        let mut chars = kind.chars();
+        let kind: String = chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default();
+        format!("{} Report: {}", kind, self.team_name)
+    }
+
+    pub fn render(&self, format: ReportFormat) -> Result<String> {
+        match format {
+            ReportFormat::Markdown => Ok(self.to_markdown()),
+            ReportFormat::Html => Ok(self.to_html()),
+            ReportFormat::Json => Ok(serde_json::to_string_pretty(self)?),
+        }
+    }
+
+    fn metric_rows(&self) -> Vec<(&'static str, String)> {
+        let mut rows = vec![
+            ("Status updates", self.update_count().to_string()),
+            ("Members reporting", format!("{} of {}", self.reporting_members(), self.members.len())),
+            ("Blockers", self.blockers.len().to_string()),
+            ("Achievements", self.achievements.len().to_string()),
+        ];
+        if let Some(metrics) = &self.metrics {
+            rows.push(("Active members", metrics.active_members.to_string()));
+            rows.push(("Completed tasks", metrics.completed_tasks.to_string()));
+            if let Some(satisfaction) = metrics.average_satisfaction {
+                rows.push(("Average satisfaction", format!("{:.1}/10", satisfaction)));
+            }
+            if let Some(velocity) = metrics.velocity {
+                rows.push(("Velocity", format!("{:.1}", velocity)));
+            }
+        }
+        if let Some(score) = self.health_score {
+            rows.push(("Health score", format!("{:.1}/10", score)));
+        }
+        rows
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut metrics: Vec<String> = vec!["| Metric | Value |".to_string(), "| --- | --- |".to_string()];
+        metrics.extend(self.metric_rows().into_iter().map(|(name, value)| format!("| {} | {} |", name, value)));
+        if !self.concerns.is_empty() {
+            metrics.push("\n**Needs attention:**\n".to_string());
+            metrics.extend(self.concerns.iter().map(|concern| format!("- {}", concern)));
+        }
+
+        let items = |items: &[ReportItem], empty: &str| {
+            if items.is_empty() {
+                return format!("_{}_", empty);
+            }
+            items.iter()
+                .map(|item| format!("- **{}**: {} ({})", item.member, item.text, item.reported_at.format("%Y-%m-%d")))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let members = self.members.iter().map(|member| {
+            let mut lines = vec![format!("### {} ({})", member.name, member.role)];
+            if !member.projects.is_empty() {
+                lines.push(format!("Projects: {}\n", member.projects.join(", ")));
+            }
+            if member.updates.is_empty() {
+                lines.push("_No status updates in this period_".to_string());
+            }
+            for update in &member.updates {
+                let mood = update.mood.as_deref().map(|m| format!(" _(mood: {})_", m)).unwrap_or_default();
+                lines.push(format!("- {}: {}{}", update.timestamp.format("%Y-%m-%d"), update.content, mood));
+            }
+            lines.join("\n")
+        }).collect::<Vec<_>>();
+
+        let projects = if self.projects.is_empty() {
+            "_No projects_".to_string()
+        } else {
+            self.projects.iter().map(|project| {
+                let members = if project.members.is_empty() { "nobody assigned".to_string() } else { project.members.join(", ") };
+                format!("- **{}** ({}): {}", project.name, project.status, members)
+            }).collect::<Vec<_>>().join("\n")
+        };
+
+        let summary = self.summary.as_deref()
+            .map(|text| format!("## Summary\n\n{}\n\n", text.trim()))
+            .unwrap_or_default();
+
+        let generated_at = self.generated_at.format("%Y-%m-%d %H:%M UTC").to_string();
+        let members = if members.is_empty() { "_No team members_".to_string() } else { members.join("\n\n") };
+        fill(MARKDOWN_TEMPLATE, &[
+            ("title", &self.title()),
+            ("time_period", &self.time_period()),
+            ("generated_at", &generated_at),
+            ("summary", &summary),
+            ("metrics", &metrics.join("\n")),
+            ("blockers", &items(&self.blockers, "No blockers reported")),
+            ("achievements", &items(&self.achievements, "No achievements reported")),
+            ("members", &members),
+            ("projects", &projects),
+        ])
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut metrics = vec!["<table>".to_string()];
+        metrics.extend(self.metric_rows().into_iter()
+            .map(|(name, value)| format!("<tr><th>{}</th><td>{}</td></tr>", name, escape_html(&value))));
+        metrics.push("</table>".to_string());
+        if !self.concerns.is_empty() {
+            metrics.push("<p class=\"concern\">Needs attention:</p>\n<ul class=\"concern\">".to_string());
+            metrics.extend(self.concerns.iter().map(|concern| format!("<li>{}</li>", concern)));
+            metrics.push("</ul>".to_string());
+        }
+
+        let items = |items: &[ReportItem], class: &str, empty: &str| {
+            if items.is_empty() {
+                return format!("<p class=\"empty\">{}</p>", empty);
+            }
+            let entries: Vec<String> = items.iter().map(|item| format!(
+                "<li class=\"{}\"><strong>{}</strong>: {} ({})</li>",
+                class, escape_html(&item.member), escape_html(&item.text), item.reported_at.format("%Y-%m-%d")
+            )).collect();
+            format!("<ul>\n{}\n</ul>", entries.join("\n"))
+        };
+
+        let members = self.members.iter().map(|member| {
+            let mut lines = vec![
+                "<div class=\"member\">".to_string(),
+                format!("<h3>{} ({})</h3>", escape_html(&member.name), escape_html(&member.role)),
+            ];
+            if !member.projects.is_empty() {
+                lines.push(format!("<p>Projects: {}</p>", escape_html(&member.projects.join(", "))));
+            }
+            if member.updates.is_empty() {
+                lines.push("<p class=\"empty\">No status updates in this period</p>".to_string());
+            } else {
+                lines.push("<ul>".to_string());
+                for update in &member.updates {
+                    let mood = update.mood.as_deref().map(|m| format!(" <em>(mood: {})</em>", escape_html(m))).unwrap_or_default();
+                    lines.push(format!("<li>{}: {}{}</li>", update.timestamp.format("%Y-%m-%d"), escape_html(&update.content), mood));
+                }
+                lines.push("</ul>".to_string());
+            }
+            lines.push("</div>".to_string());
+            lines.join("\n")
+        }).collect::<Vec<_>>();
+
+        let projects = if self.projects.is_empty() {
+            "<p class=\"empty\">No projects</p>".to_string()
+        } else {
+            let entries: Vec<String> = self.projects.iter().map(|project| {
+                let members = if project.members.is_empty() { "nobody assigned".to_string() } else { project.members.join(", ") };
+                format!("<li><strong>{}</strong> ({}): {}</li>", escape_html(&project.name), project.status, escape_html(&members))
+            }).collect();
+            format!("<ul>\n{}\n</ul>", entries.join("\n"))
+        };
+
+        let summary = self.summary.as_deref()
+            .map(|text| {
+                let paragraphs: Vec<String> = text.trim().split("\n\n").map(|p| format!("<p>{}</p>", escape_html(p.trim()))).collect();
+                format!("<h2>Summary</h2>\n{}\n", paragraphs.join("\n"))
+            })
+            .unwrap_or_default();
+
+        let generated_at = self.generated_at.format("%Y-%m-%d %H:%M UTC").to_string();
+        let members = if members.is_empty() { "<p class=\"empty\">No team members</p>".to_string() } else { members.join("\n") };
+        fill(HTML_TEMPLATE, &[
+            ("title", &escape_html(&self.title())),
+            ("time_period", &self.time_period()),
+            ("generated_at", &generated_at),
+            ("summary", &summary),
+            ("metrics", &metrics.join("\n")),
+            ("blockers", &items(&self.blockers, "blocker", "No blockers reported")),
+            ("achievements", &items(&self.achievements, "achievement", "No achievements reported")),
+            ("members", &members),
+            ("projects", &projects),
+        ])
+    }
+}
+
+/// Replaces each `{name}` in `template` with its value in one pass, so text
+/// inside a value is never read as another placeholder. Also used for the
+/// report prompt. Braces that are not
+/// a known name, such as the CSS in the HTML template, are left as they are.
+pub(crate) fn fill(template: &str, values: &[(&str, &str)]) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let value = after.find('}')
+            .and_then(|end| values.iter().find(|(name, _)| *name == &after[..end]));
+        match value {
+            Some((name, value)) => {
+                output.push_str(value);
+                rest = &after[name.len() + 1..];
+            }
+            None => {
+                output.push('{');
+                rest = after;
+            }
+        }
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Escapes text for use in HTML element content and attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
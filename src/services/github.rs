@@ -1,6 +1,7 @@
 use anyhow::Result;
 use crate::claude::ClaudeCliManager;
 
+#[derive(Default)]
 pub struct GitHubService;
 
 impl GitHubService {
@@ -1,6 +1,138 @@
 use anyhow::Result;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::claude::structured::{self, Structured, StructuredResponse};
 use crate::claude::ClaudeCliManager;
+pub use crate::models::is_ticket_key;
 
+/// A Jira issue as returned by the Jira MCP tools.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraTicket {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_timestamp")]
+    pub updated: Option<DateTime<Utc>>,
+}
+
+/// A list of tickets, wrapped in an object so the response is a single JSON object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraTickets {
+    pub tickets: Vec<JiraTicket>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JiraProjectMetrics {
+    pub project: String,
+    pub created: u32,
+    pub completed: u32,
+    pub in_progress: u32,
+    #[serde(default)]
+    pub average_resolution_days: Option<f32>,
+    #[serde(default)]
+    pub velocity: Option<f32>,
+    #[serde(default)]
+    pub blockers: Vec<JiraTicket>,
+}
+
+const TICKET_SCHEMA: &str = r#"{
+  "type": "object",
+  "required": ["key", "summary", "status"],
+  "properties": {
+    "key": {"type": "string", "description": "Issue key such as PROJ-123"},
+    "summary": {"type": "string"},
+    "status": {"type": "string"},
+    "assignee": {"type": ["string", "null"], "description": "Display name, null when unassigned"},
+    "priority": {"type": ["string", "null"]},
+    "updated": {"type": ["string", "null"], "description": "ISO 8601 timestamp of the last update"}
+  }
+}"#;
+
+impl StructuredResponse for JiraTicket {
+    fn schema() -> String {
+        TICKET_SCHEMA.to_string()
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        if !is_ticket_key(&self.key) {
+            return Err(format!("key: expected an issue key like PROJ-123, got {:?}", self.key));
+        }
+        if self.summary.trim().is_empty() {
+            return Err(format!("{}: summary must not be empty", self.key));
+        }
+        if self.status.trim().is_empty() {
+            return Err(format!("{}: status must not be empty", self.key));
+        }
+        Ok(())
+    }
+}
+
+impl StructuredResponse for JiraTickets {
+    fn schema() -> String {
+        format!(
+            r#"{{"type": "object", "required": ["tickets"], "properties": {{"tickets": {{"type": "array", "items": {}}}}}}}"#,
+            TICKET_SCHEMA
+        )
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        for (i, ticket) in self.tickets.iter().enumerate() {
+            ticket.validate().map_err(|e| format!("tickets[{}]: {}", i, e))?;
+        }
+        Ok(())
+    }
+}
+
+impl StructuredResponse for JiraProjectMetrics {
+    fn schema() -> String {
+        format!(
+            r#"{{
+  "type": "object",
+  "required": ["project", "created", "completed", "in_progress"],
+  "properties": {{
+    "project": {{"type": "string"}},
+    "created": {{"type": "integer", "minimum": 0}},
+    "completed": {{"type": "integer", "minimum": 0}},
+    "in_progress": {{"type": "integer", "minimum": 0}},
+    "average_resolution_days": {{"type": ["number", "null"]}},
+    "velocity": {{"type": ["number", "null"], "description": "Story points completed in the period"}},
+    "blockers": {{"type": "array", "items": {}}}
+  }}
+}}"#,
+            TICKET_SCHEMA
+        )
+    }
+
+    fn validate(&self) -> std::result::Result<(), String> {
+        for (i, ticket) in self.blockers.iter().enumerate() {
+            ticket.validate().map_err(|e| format!("blockers[{}]: {}", i, e))?;
+        }
+        Ok(())
+    }
+}
+
+/// Accepts RFC 3339, Jira's own `2025-08-01T10:15:30.000+0000` and plain dates.
+fn deserialize_timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Option<DateTime<Utc>>, D::Error> {
+    let Some(value) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z"))
+        .map(|t| t.with_timezone(&Utc))
+        .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d").map(|d| d.and_time(Default::default()).and_utc()))
+        .map(Some)
+        .map_err(|_| serde::de::Error::custom(format!("invalid timestamp {:?}, expected ISO 8601", value)))
+}
+
+#[derive(Default)]
 pub struct JiraService;
 
 impl JiraService {
@@ -8,87 +140,83 @@ impl JiraService {
         Self
     }
 
-    pub async fn get_work_items_for_week(&self, projects: &[String]) -> Result<String> {
+    async fn request<T: StructuredResponse>(&self, prompt: &str) -> Result<Structured<T>> {
         let claude = ClaudeCliManager::get_instance().await?;
-        
+        structured::request(prompt, |prompt| {
+            let claude = claude.clone();
+            async move { claude.send_prompt(&prompt).await }
+        }).await
+    }
+
+    pub async fn get_work_items_for_week(&self, projects: &[String]) -> Result<Structured<Vec<JiraTicket>>> {
         let prompt = format!(
             "Get this week's Jira work items for all users in projects {} - Use Jira MCP tools to retrieve information. \
-            Please provide a summary of tickets, their status, assignees, and any blockers.",
+            Include every ticket with its status, assignee, priority and when it was last updated.",
             projects.join(", ")
         );
-        
-        claude.send_prompt(&prompt).await
+
+        Ok(self.request::<JiraTickets>(&prompt).await?.map(|t| t.tickets))
     }
 
-    pub async fn get_user_tickets(&self, user_email: &str, project: &str) -> Result<String> {
-        let claude = ClaudeCliManager::get_instance().await?;
-        
+    pub async fn get_user_tickets(&self, user_email: &str, project: &str) -> Result<Structured<Vec<JiraTicket>>> {
         let prompt = format!(
             "Get current active Jira tickets for user {} in project {} - Use Jira MCP tools. \
-            Include ticket status, priority, and estimated completion time.",
+            Include ticket status, priority, and when each was last updated.",
             user_email, project
         );
-        
-        claude.send_prompt(&prompt).await
+
+        Ok(self.request::<JiraTickets>(&prompt).await?.map(|t| t.tickets))
     }
 
-    pub async fn update_ticket_status(&self, ticket_key: &str, new_status: &str, comment: Option<&str>) -> Result<String> {
-        let claude = ClaudeCliManager::get_instance().await?;
-        
+    /// Returns the ticket as it is after the update.
+    pub async fn update_ticket_status(&self, ticket_key: &str, new_status: &str, comment: Option<&str>) -> Result<Structured<JiraTicket>> {
         let comment_part = comment
             .map(|c| format!(" with comment: '{}'", c))
             .unwrap_or_default();
-        
+
         let prompt = format!(
             "Update Jira ticket {} to status '{}'{} - Use Jira MCP tools to perform the update. \
-            Confirm the update was successful and provide the new ticket status.",
+            Then return the ticket with its new status.",
             ticket_key, new_status, comment_part
         );
-        
-        claude.send_prompt(&prompt).await
+
+        self.request(&prompt).await
     }
 
-    pub async fn create_ticket(&self, project: &str, summary: &str, description: &str, issue_type: &str) -> Result<String> {
-        let claude = ClaudeCliManager::get_instance().await?;
-        
+    pub async fn create_ticket(&self, project: &str, summary: &str, description: &str, issue_type: &str) -> Result<Structured<JiraTicket>> {
         let prompt = format!(
             "Create a new Jira ticket in project {} with the following details: \
             Summary: '{}' \
             Description: '{}' \
             Issue Type: '{}' \
-            Use Jira MCP tools to create the ticket and return the ticket key.",
+            Use Jira MCP tools to create the ticket and return the created ticket.",
             project, summary, description, issue_type
         );
-        
-        claude.send_prompt(&prompt).await
+
+        self.request(&prompt).await
     }
 
-    pub async fn get_project_metrics(&self, project: &str, period: &str) -> Result<String> {
-        let claude = ClaudeCliManager::get_instance().await?;
-        
+    pub async fn get_project_metrics(&self, project: &str, period: &str) -> Result<Structured<JiraProjectMetrics>> {
         let prompt = format!(
             "Generate project metrics for Jira project {} for the {} period. \
             Use Jira MCP tools to gather data on: \
             - Number of tickets created, completed, and in progress \
-            - Average resolution time \
+            - Average resolution time in days \
             - Team velocity \
-            - Blockers and impediments \
-            Provide a comprehensive analysis.",
+            - Tickets that are blocked",
             project, period
         );
-        
-        claude.send_prompt(&prompt).await
+
+        self.request(&prompt).await
     }
 
-    pub async fn search_tickets(&self, jql_query: &str) -> Result<String> {
-        let claude = ClaudeCliManager::get_instance().await?;
-        
+    pub async fn search_tickets(&self, jql_query: &str) -> Result<Structured<Vec<JiraTicket>>> {
         let prompt = format!(
             "Search Jira tickets using JQL query: '{}' - Use Jira MCP tools. \
-            Provide a formatted list of matching tickets with key details.",
+            Return every matching ticket.",
             jql_query
         );
-        
-        claude.send_prompt(&prompt).await
+
+        Ok(self.request::<JiraTickets>(&prompt).await?.map(|t| t.tickets))
     }
-}
\ No newline at end of file
+}
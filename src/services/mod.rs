@@ -3,7 +3,7 @@ pub mod slack;
 pub mod github;
 pub mod email;
 
-pub use jira::JiraService;
+pub use jira::{JiraProjectMetrics, JiraService, JiraTicket, JiraTickets};
 pub use slack::SlackService;
 pub use github::GitHubService;
 pub use email::EmailService;
\ No newline at end of file
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use crate::claude::ClaudeCliManager;
 
+#[derive(Default)]
 pub struct SlackService;
 
 impl SlackService {
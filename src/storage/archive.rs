@@ -0,0 +1,218 @@
+use crate::models::TeamMember;
+use crate::storage::{DataCollection, Storage, StorageError};
+use crate::storage::migrations::{self, CURRENT_SCHEMA_VERSION};
+use anyhow::{anyhow, bail, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Version of the archive envelope itself, independent of the schema
+/// version of the collections inside it.
+pub const ARCHIVE_FORMAT_VERSION: u32 = 1;
+
+/// A single-file bundle of every collection in a data directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Archive {
+    pub format_version: u32,
+    pub schema_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub collections: BTreeMap<String, Value>,
+}
+
+impl Archive {
+    pub fn item_count(&self) -> usize {
+        self.collections.values().map(item_count).sum()
+    }
+}
+
+fn item_count(collection: &Value) -> usize {
+    collection.get("items").and_then(Value::as_object).map_or(0, Map::len)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Keep existing records and add or update records by id.
+    Merge,
+    /// Overwrite every collection contained in the archive.
+    Replace,
+}
+
+impl FromStr for ImportMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "merge" => Ok(ImportMode::Merge),
+            "replace" => Ok(ImportMode::Replace),
+            other => Err(anyhow!("unknown import mode {:?}, expected merge or replace", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    KeptExisting,
+    TookIncoming,
+}
+
+/// A record present on both sides with different contents.
+#[derive(Debug, Clone)]
+pub struct Conflict {
+    pub collection: String,
+    pub id: String,
+    pub existing_updated_at: Option<DateTime<Utc>>,
+    pub incoming_updated_at: Option<DateTime<Utc>>,
+    pub resolution: Resolution,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportReport {
+    pub collection: String,
+    pub added: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub replaced: usize,
+    pub conflicts: Vec<Conflict>,
+}
+
+/// Collects every collection in `store`, upgraded to the current schema.
+pub fn export<S: Storage>(store: &S) -> Result<Archive> {
+    let mut collections = BTreeMap::new();
+    for key in store.list_keys()? {
+        if let Some(data) = store.load::<Value>(&key)? {
+            if migrations::is_collection(&data) {
+                collections.insert(key, data);
+            }
+        }
+    }
+
+    Ok(Archive {
+        format_version: ARCHIVE_FORMAT_VERSION,
+        schema_version: CURRENT_SCHEMA_VERSION,
+        exported_at: Utc::now(),
+        collections,
+    })
+}
+
+/// Restores `archive` into `store`. In merge mode records are matched by id;
+/// when both sides changed the one with the later `updated_at` wins and the
+/// conflict is reported. With `dry_run` set nothing is written.
+pub fn import<S: Storage>(store: &S, archive: Archive, mode: ImportMode, dry_run: bool) -> Result<Vec<ImportReport>> {
+    if archive.format_version > ARCHIVE_FORMAT_VERSION {
+        bail!(
+            "archive format version {} is newer than supported version {}",
+            archive.format_version, ARCHIVE_FORMAT_VERSION
+        );
+    }
+
+    let mut reports = Vec::new();
+    for (key, mut incoming) in archive.collections {
+        if !migrations::is_collection(&incoming) {
+            bail!("{} in the archive is not a collection", key);
+        }
+        migrations::upgrade(&key, &mut incoming)?;
+
+        let report = match mode {
+            ImportMode::Replace => {
+                check_members(&key, &incoming, &incoming)?;
+                let report = ImportReport {
+                    collection: key.clone(),
+                    replaced: item_count(&incoming),
+                    ..Default::default()
+                };
+                if !dry_run {
+                    store.save(&key, &incoming)?;
+                }
+                report
+            }
+            ImportMode::Merge if dry_run => {
+                let mut existing = store.load::<Value>(&key)?.unwrap_or(Value::Null);
+                let report = merge_collection(&key, &mut existing, &incoming);
+                check_members(&key, &existing, &incoming)?;
+                report
+            }
+            ImportMode::Merge => store.update(&key, |existing: &mut Value| {
+                let report = merge_collection(&key, existing, &incoming);
+                check_members(&key, existing, &incoming)?;
+                Ok(report)
+            })?,
+        };
+        reports.push(report);
+    }
+    Ok(reports)
+}
+
+fn merge_collection(key: &str, existing: &mut Value, incoming: &Value) -> ImportReport {
+    let mut report = ImportReport { collection: key.to_string(), ..Default::default() };
+
+    if !migrations::is_collection(existing) {
+        *existing = incoming.clone();
+        existing["items"] = Value::Object(Map::new());
+    }
+    let empty = Map::new();
+    let incoming_items = incoming.get("items").and_then(Value::as_object).unwrap_or(&empty);
+    let Some(existing_items) = existing.get_mut("items").and_then(Value::as_object_mut) else {
+        return report;
+    };
+
+    for (id, item) in incoming_items {
+        let Some(current) = existing_items.get(id) else {
+            existing_items.insert(id.clone(), item.clone());
+            report.added += 1;
+            continue;
+        };
+        if current == item {
+            report.unchanged += 1;
+            continue;
+        }
+
+        let existing_updated_at = updated_at(current);
+        let incoming_updated_at = updated_at(item);
+        let resolution = match (existing_updated_at, incoming_updated_at) {
+            (Some(existing), Some(incoming)) if incoming > existing => Resolution::TookIncoming,
+            _ => Resolution::KeptExisting,
+        };
+
+        if resolution == Resolution::TookIncoming {
+            existing_items.insert(id.clone(), item.clone());
+            report.updated += 1;
+        }
+        report.conflicts.push(Conflict {
+            collection: key.to_string(),
+            id: id.clone(),
+            existing_updated_at,
+            incoming_updated_at,
+            resolution,
+        });
+    }
+
+    if report.added + report.updated > 0 {
+        existing["last_updated"] = serde_json::to_value(Utc::now()).unwrap_or(Value::Null);
+    }
+    report
+}
+
+/// Applies the same checks as `TeamRepository::save_team_member` to the
+/// members of `collection` that came from the archive.
+fn check_members(key: &str, collection: &Value, incoming: &Value) -> Result<(), StorageError> {
+    if key != "team_members" {
+        return Ok(());
+    }
+    let members: DataCollection<TeamMember> = serde_json::from_value(collection.clone())?;
+    let imported = incoming.get("items").and_then(Value::as_object);
+    for (id, member) in &members.items {
+        if !imported.is_some_and(|items| items.contains_key(id)) {
+            continue;
+        }
+        member.validate()
+            .and_then(|_| member.check_unique(members.items.values()))
+            .map_err(|source| StorageError::InvalidMember { id: id.clone(), name: member.name.clone(), source })?;
+    }
+    Ok(())
+}
+
+fn updated_at(item: &Value) -> Option<DateTime<Utc>> {
+    item.get("updated_at")?.as_str()?.parse().ok()
+}
@@ -0,0 +1,103 @@
+use crate::config::StorageConfig;
+use crate::storage::{JsonStore, SqliteStore, Storage, StorageError};
+use crate::storage::error::Result;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The storage implementation selected by `storage.data_format`. Tests that
+/// need a throwaway store use `InMemoryStore` directly.
+pub enum StorageBackend {
+    Json(JsonStore),
+    Sqlite(SqliteStore),
+}
+
+impl StorageBackend {
+    pub fn from_config<P: AsRef<Path>>(config: &StorageConfig, data_dir: P) -> Result<Self> {
+        match config.data_format.as_str() {
+            "json" => Ok(StorageBackend::Json(
+                JsonStore::new(data_dir)?.with_lock_timeout(config.lock_timeout()),
+            )),
+            "sqlite" => Ok(StorageBackend::Sqlite(
+                SqliteStore::open(data_dir, config.lock_timeout())?,
+            )),
+            other => Err(StorageError::UnsupportedFormat(other.to_string())),
+        }
+    }
+}
+
+macro_rules! dispatch {
+    ($self:ident, $store:ident => $call:expr) => {
+        match $self {
+            StorageBackend::Json($store) => $call,
+            StorageBackend::Sqlite($store) => $call,
+        }
+    };
+}
+
+impl Storage for StorageBackend {
+    fn save<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
+        dispatch!(self, store => store.save(key, data))
+    }
+
+    fn load<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<Option<T>> {
+        dispatch!(self, store => store.load(key))
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        dispatch!(self, store => store.delete(key))
+    }
+
+    fn list_keys(&self) -> Result<Vec<String>> {
+        dispatch!(self, store => store.list_keys())
+    }
+
+    fn load_raw(&self, key: &str) -> Result<Option<serde_json::Value>> {
+        dispatch!(self, store => store.load_raw(key))
+    }
+
+    fn update<T, R, F>(&self, key: &str, f: F) -> Result<R>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Default,
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        dispatch!(self, store => store.update(key, f))
+    }
+
+    fn put_item<T>(&self, collection: &str, id: &str, item: &T) -> Result<()>
+    where
+        T: Serialize + DeserializeOwned + Clone,
+    {
+        dispatch!(self, store => store.put_item(collection, id, item))
+    }
+
+    fn get_item<T: DeserializeOwned>(&self, collection: &str, id: &str) -> Result<Option<T>> {
+        dispatch!(self, store => store.get_item(collection, id))
+    }
+
+    fn remove_item<T>(&self, collection: &str, id: &str) -> Result<Option<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        dispatch!(self, store => store.remove_item(collection, id))
+    }
+
+    fn list_items<T: DeserializeOwned>(&self, collection: &str) -> Result<Vec<T>> {
+        dispatch!(self, store => store.list_items(collection))
+    }
+
+    fn find_items<T: DeserializeOwned>(&self, collection: &str, field: &str, value: &str) -> Result<Vec<T>> {
+        dispatch!(self, store => store.find_items(collection, field, value))
+    }
+
+    fn find_items_in_range<T: DeserializeOwned>(
+        &self,
+        collection: &str,
+        field: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<T>> {
+        dispatch!(self, store => store.find_items_in_range(collection, field, start, end))
+    }
+}
@@ -0,0 +1,83 @@
+use crate::models::MemberError;
+use crate::storage::resolver::ResolveError;
+use std::io;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub type Result<T, E = StorageError> = std::result::Result<T, E>;
+
+/// Failures from a `Storage` implementation.
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("{} does not exist", .0.display())]
+    Missing(PathBuf),
+    #[error("failed to {action} {}: {source}", path.display())]
+    Io {
+        action: &'static str,
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("{} is corrupt: {source}", path.display())]
+    Corrupt {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+    #[error("timed out after {waited:?} waiting for the lock on {}; another timeless process may be writing to the data directory", path.display())]
+    LockTimeout { path: PathBuf, waited: Duration },
+    #[error("{collection} has schema version {found}, but this build only understands up to {supported}; upgrade timeless")]
+    UnsupportedSchema { collection: String, found: u32, supported: u32 },
+    #[error("failed to migrate {collection} to schema version {version}: {message}")]
+    Migration { collection: String, version: u32, message: String },
+    #[error("stored data does not have the expected shape: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("{0}")]
+    InvalidKey(String),
+    /// `storage.data_format` named a backend this build does not have.
+    #[error("unsupported storage format {0:?}")]
+    UnsupportedFormat(String),
+    #[error("database error: {0}")]
+    Database(#[from] rusqlite::Error),
+    #[error("{0} was poisoned by an earlier panic")]
+    Poisoned(&'static str),
+    /// A member handed to `update` failed validation or clashed with another
+    /// member; nothing was written.
+    #[error("member {name} ({id}) was rejected: {source}")]
+    InvalidMember {
+        id: String,
+        name: String,
+        #[source]
+        source: MemberError,
+    },
+}
+
+impl StorageError {
+    /// Wraps an I/O error on `path`, reporting a missing file or directory as
+    /// `Missing`.
+    pub fn io(action: &'static str, path: impl Into<PathBuf>, source: io::Error) -> Self {
+        let path = path.into();
+        if source.kind() == io::ErrorKind::NotFound {
+            StorageError::Missing(path)
+        } else {
+            StorageError::Io { action, path, source }
+        }
+    }
+}
+
+/// Failures from `TeamRepository`: either the store failed or the record
+/// was rejected.
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error(transparent)]
+    Member(#[from] MemberError),
+    #[error("invalid assignment: {0}")]
+    InvalidAssignment(String),
+    #[error(transparent)]
+    Resolve(#[from] ResolveError),
+    /// No project, blocker or other record matched `query`.
+    #[error("no {kind} matching {query:?}")]
+    NotFound { kind: &'static str, query: String },
+}
@@ -1,61 +1,211 @@
-use crate::storage::Storage;
-use anyhow::Result;
+use crate::storage::{Storage, StorageError};
+use crate::storage::error::Result;
+use crate::storage::migrations::{self, CURRENT_SCHEMA_VERSION};
+use log::warn;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::fs;
-use std::path::PathBuf;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(20);
 
 pub struct JsonStore {
     data_dir: PathBuf,
+    lock_timeout: Duration,
 }
 
 impl JsonStore {
-    pub fn new(data_dir: &str) -> Result<Self> {
-        let data_dir = PathBuf::from(data_dir);
+    pub fn new<P: AsRef<Path>>(data_dir: P) -> Result<Self> {
+        let data_dir = data_dir.as_ref().to_path_buf();
         if !data_dir.exists() {
-            fs::create_dir_all(&data_dir)?;
+            fs::create_dir_all(&data_dir).map_err(|e| StorageError::io("create", &data_dir, e))?;
         }
-        Ok(JsonStore { data_dir })
+        Ok(JsonStore { data_dir, lock_timeout: DEFAULT_LOCK_TIMEOUT })
+    }
+    
+    pub fn with_lock_timeout(mut self, lock_timeout: Duration) -> Self {
+        self.lock_timeout = lock_timeout;
+        self
     }
     
     fn get_file_path(&self, key: &str) -> PathBuf {
         self.data_dir.join(format!("{}.json", key))
     }
+    
+    fn get_backup_path(&self, key: &str) -> PathBuf {
+        self.data_dir.join(format!("{}.json.bak", key))
+    }
+    
+    fn get_lock_path(&self, key: &str) -> PathBuf {
+        self.data_dir.join(format!("{}.lock", key))
+    }
+    
+    /// Takes an exclusive advisory lock on `<key>.lock`, retrying until the
+    /// configured timeout. The lock is released when the returned file is
+    /// dropped.
+    fn lock(&self, key: &str) -> Result<File> {
+        let path = self.get_lock_path(key);
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(|e| StorageError::io("open lock file", &path, e))?;
+        
+        let started = Instant::now();
+        loop {
+            match file.try_lock() {
+                Ok(()) => return Ok(file),
+                Err(TryLockError::WouldBlock) if started.elapsed() < self.lock_timeout => {
+                    std::thread::sleep(LOCK_RETRY_INTERVAL);
+                }
+                Err(TryLockError::WouldBlock) => {
+                    return Err(StorageError::LockTimeout { path, waited: self.lock_timeout });
+                }
+                Err(TryLockError::Error(e)) => {
+                    return Err(StorageError::io("lock", &path, e));
+                }
+            }
+        }
+    }
+    
+    /// Writes `contents` to a temporary file, fsyncs it and renames it over
+    /// `path`, so readers only ever see the old or the new file in full.
+    fn write_atomic(&self, path: &Path, contents: &[u8]) -> Result<()> {
+        let mut tmp_name = path.as_os_str().to_owned();
+        tmp_name.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_name);
+        
+        let write = || -> std::io::Result<()> {
+            let mut file = File::create(&tmp_path)?;
+            file.write_all(contents)?;
+            file.sync_all()
+        };
+        write().map_err(|e| StorageError::io("write", &tmp_path, e))?;
+        
+        fs::rename(&tmp_path, path).map_err(|e| StorageError::io("replace", path, e))?;
+        self.sync_data_dir();
+        Ok(())
+    }
+    
+    /// Copies the current file to `<key>.json.bak` before it is overwritten,
+    /// as long as it still holds valid JSON.
+    fn refresh_backup(&self, key: &str) -> Result<()> {
+        let path = self.get_file_path(key);
+        let Ok(current) = fs::read(&path) else {
+            return Ok(());
+        };
+        
+        if serde_json::from_slice::<serde::de::IgnoredAny>(&current).is_ok() {
+            self.write_atomic(&self.get_backup_path(key), &current)?;
+        }
+        Ok(())
+    }
+    
+    #[cfg(unix)]
+    fn sync_data_dir(&self) {
+        // Persist the rename itself; failure here only weakens durability.
+        if let Ok(dir) = File::open(&self.data_dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    
+    #[cfg(not(unix))]
+    fn sync_data_dir(&self) {}
+    
+    fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<Option<T>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        
+        let content = fs::read_to_string(path).map_err(|e| StorageError::io("read", path, e))?;
+        let data: T = serde_json::from_str(&content)
+            .map_err(|source| StorageError::Corrupt { path: path.to_path_buf(), source })?;
+        Ok(Some(data))
+    }
+    
+    /// Restores `<key>.json` from its backup after the primary file turned out
+    /// to be missing or unreadable.
+    fn recover_from_backup<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<Option<T>> {
+        let backup_path = self.get_backup_path(key);
+        let Some(data) = Self::read_json(&backup_path)? else {
+            return Ok(None);
+        };
+        
+        let contents = fs::read(&backup_path).map_err(|e| StorageError::io("read", &backup_path, e))?;
+        self.write_atomic(&self.get_file_path(key), &contents)?;
+        Ok(Some(data))
+    }
 }
 
 impl Storage for JsonStore {
     fn save<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
         let path = self.get_file_path(key);
         let json = serde_json::to_string_pretty(data)?;
-        fs::write(path, json)?;
-        Ok(())
+        self.refresh_backup(key)?;
+        self.write_atomic(&path, json.as_bytes())
     }
     
     fn load<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<Option<T>> {
-        let path = self.get_file_path(key);
-        if !path.exists() {
+        let Some(mut data) = self.load_raw(key)? else {
             return Ok(None);
+        };
+        migrations::upgrade(key, &mut data)?;
+        Ok(Some(serde_json::from_value(data)?))
+    }
+    
+    fn load_raw(&self, key: &str) -> Result<Option<serde_json::Value>> {
+        let path = self.get_file_path(key);
+        match Self::read_json(&path) {
+            Ok(Some(data)) => Ok(Some(data)),
+            Ok(None) => {
+                let recovered = self.recover_from_backup(key)?;
+                if recovered.is_some() {
+                    warn!("{} was missing, restored it from backup", path.display());
+                }
+                Ok(recovered)
+            }
+            Err(e) => match self.recover_from_backup(key) {
+                Ok(Some(data)) => {
+                    warn!("{}; restored it from backup", e);
+                    Ok(Some(data))
+                }
+                _ => Err(e),
+            },
         }
-        
-        let content = fs::read_to_string(path)?;
-        let data: T = serde_json::from_str(&content)?;
-        Ok(Some(data))
     }
     
     fn delete(&self, key: &str) -> Result<()> {
-        let path = self.get_file_path(key);
-        if path.exists() {
-            fs::remove_file(path)?;
+        for path in [self.get_file_path(key), self.get_backup_path(key)] {
+            if path.exists() {
+                fs::remove_file(&path).map_err(|e| StorageError::io("remove", &path, e))?;
+            }
         }
         Ok(())
     }
     
+    fn update<T, R, F>(&self, key: &str, f: F) -> Result<R>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Default,
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        let _lock = self.lock(key)?;
+        let mut data: T = self.load(key)?.unwrap_or_default();
+        let result = f(&mut data)?;
+        self.save(key, &data)?;
+        Ok(result)
+    }
+    
     fn list_keys(&self) -> Result<Vec<String>> {
         let mut keys = Vec::new();
-        for entry in fs::read_dir(&self.data_dir)? {
-            let entry = entry?;
+        let entries = fs::read_dir(&self.data_dir).map_err(|e| StorageError::io("list", &self.data_dir, e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| StorageError::io("list", &self.data_dir, e))?;
             let path = entry.path();
-            if path.is_file() && path.extension().map_or(false, |ext| ext == "json") {
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
                 if let Some(stem) = path.file_stem() {
                     if let Some(key) = stem.to_str() {
                         keys.push(key.to_string());
@@ -71,6 +221,8 @@ impl Storage for JsonStore {
 pub struct DataCollection<T> {
     pub items: HashMap<String, T>,
     pub last_updated: chrono::DateTime<chrono::Utc>,
+    #[serde(default)]
+    pub schema_version: u32,
 }
 
 impl<T> Default for DataCollection<T> {
@@ -78,6 +230,7 @@ impl<T> Default for DataCollection<T> {
         DataCollection {
             items: HashMap::new(),
             last_updated: chrono::Utc::now(),
+            schema_version: CURRENT_SCHEMA_VERSION,
         }
     }
 }
@@ -0,0 +1,70 @@
+use crate::storage::{migrations, Storage, StorageError};
+use crate::storage::error::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// Keeps every key as a JSON value in memory. Nothing is written to disk, so
+/// it suits tests and dry runs.
+#[derive(Default)]
+pub struct InMemoryStore {
+    data: Mutex<HashMap<String, Value>>,
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    fn data(&self) -> Result<MutexGuard<'_, HashMap<String, Value>>> {
+        self.data.lock().map_err(|_| StorageError::Poisoned("in-memory store"))
+    }
+}
+
+impl Storage for InMemoryStore {
+    fn save<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
+        let value = serde_json::to_value(data)?;
+        self.data()?.insert(key.to_string(), value);
+        Ok(())
+    }
+    
+    fn load<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<Option<T>> {
+        let Some(mut value) = self.load_raw(key)? else {
+            return Ok(None);
+        };
+        migrations::upgrade(key, &mut value)?;
+        Ok(Some(serde_json::from_value(value)?))
+    }
+    
+    fn load_raw(&self, key: &str) -> Result<Option<Value>> {
+        Ok(self.data()?.get(key).cloned())
+    }
+    
+    fn delete(&self, key: &str) -> Result<()> {
+        self.data()?.remove(key);
+        Ok(())
+    }
+    
+    fn list_keys(&self) -> Result<Vec<String>> {
+        Ok(self.data()?.keys().cloned().collect())
+    }
+    
+    fn update<T, R, F>(&self, key: &str, f: F) -> Result<R>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Default,
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        let mut data = self.data()?;
+        let mut value: T = match data.get(key).cloned() {
+            Some(mut raw) => {
+                migrations::upgrade(key, &mut raw)?;
+                serde_json::from_value(raw)?
+            }
+            None => T::default(),
+        };
+        let result = f(&mut value)?;
+        data.insert(key.to_string(), serde_json::to_value(&value)?);
+        Ok(result)
+    }
+}
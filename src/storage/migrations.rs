@@ -0,0 +1,196 @@
+use crate::storage::{Storage, StorageError};
+use crate::storage::error::Result;
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Schema version stamped into every `DataCollection` written by this build.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+/// A single upgrade step, taking a collection from `version - 1` to `version`.
+pub struct Migration {
+    pub version: u32,
+    pub description: &'static str,
+    pub apply: fn(collection: &str, data: &mut Value) -> anyhow::Result<()>,
+}
+
+/// Every migration in ascending version order. Append new entries and bump
+/// `CURRENT_SCHEMA_VERSION` whenever a stored model changes shape. Migrations
+/// must be idempotent: records written by a newer build may already have the
+/// new shape.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "Stamp collections with a schema version",
+        apply: |_, _| Ok(()),
+    },
+    Migration {
+        version: 2,
+        description: "Add status history and archive date to projects",
+        apply: |collection, data| {
+            if collection == "projects" {
+                for project in items_mut(data) {
+                    project.entry("status_history").or_insert_with(|| Value::Array(Vec::new()));
+                    project.entry("archived_at").or_insert(Value::Null);
+                }
+            }
+            Ok(())
+        },
+    },
+];
+
+/// The schema version of a stored collection; files written before
+/// versioning was introduced count as version 0.
+pub fn schema_version(data: &Value) -> u32 {
+    data.get("schema_version")
+        .and_then(Value::as_u64)
+        .map_or(0, |v| v as u32)
+}
+
+pub fn is_collection(data: &Value) -> bool {
+    data.get("items").is_some_and(Value::is_object)
+}
+
+/// Mutable access to every item of a collection, for use inside migrations.
+pub fn items_mut(data: &mut Value) -> impl Iterator<Item = &mut Map<String, Value>> {
+    data.get_mut("items")
+        .and_then(Value::as_object_mut)
+        .into_iter()
+        .flat_map(|items| items.values_mut())
+        .filter_map(Value::as_object_mut)
+}
+
+/// Brings `data` up to `CURRENT_SCHEMA_VERSION` in place and returns the
+/// migrations that were applied. Values that are not collections are left
+/// untouched.
+pub fn upgrade(collection: &str, data: &mut Value) -> Result<Vec<&'static Migration>> {
+    if !is_collection(data) {
+        return Ok(Vec::new());
+    }
+
+    let version = schema_version(data);
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(StorageError::UnsupportedSchema {
+            collection: collection.to_string(),
+            found: version,
+            supported: CURRENT_SCHEMA_VERSION,
+        });
+    }
+
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > version) {
+        (migration.apply)(collection, data).map_err(|e| StorageError::Migration {
+            collection: collection.to_string(),
+            version: migration.version,
+            message: format!("{:#}", e),
+        })?;
+        if let Some(object) = data.as_object_mut() {
+            object.insert("schema_version".to_string(), Value::from(migration.version));
+        }
+        applied.push(migration);
+    }
+    Ok(applied)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added { path: String, value: Value },
+    Removed { path: String, value: Value },
+    Modified { path: String, old: Value, new: Value },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added { path, value } => write!(f, "+ {}: {}", path, value),
+            Change::Removed { path, value } => write!(f, "- {}: {}", path, value),
+            Change::Modified { path, old, new } => write!(f, "~ {}: {} -> {}", path, old, new),
+        }
+    }
+}
+
+/// Field-level differences between two JSON values, ignoring
+/// `last_updated` bookkeeping.
+pub fn diff(before: &Value, after: &Value) -> Vec<Change> {
+    let mut changes = Vec::new();
+    diff_into("", before, after, &mut changes);
+    changes
+}
+
+fn diff_into(path: &str, before: &Value, after: &Value, changes: &mut Vec<Change>) {
+    let join = |key: &str| if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) };
+
+    match (before, after) {
+        (Value::Object(old), Value::Object(new)) => {
+            for (key, old_value) in old {
+                if path.is_empty() && key == "last_updated" {
+                    continue;
+                }
+                match new.get(key) {
+                    Some(new_value) => diff_into(&join(key), old_value, new_value, changes),
+                    None => changes.push(Change::Removed { path: join(key), value: old_value.clone() }),
+                }
+            }
+            for (key, new_value) in new {
+                if !old.contains_key(key) {
+                    changes.push(Change::Added { path: join(key), value: new_value.clone() });
+                }
+            }
+        }
+        (old, new) if old != new => changes.push(Change::Modified {
+            path: path.to_string(),
+            old: old.clone(),
+            new: new.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Outcome of migrating one stored collection.
+pub struct MigrationReport {
+    pub key: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub applied: Vec<&'static str>,
+    pub changes: Vec<Change>,
+}
+
+impl MigrationReport {
+    pub fn is_up_to_date(&self) -> bool {
+        self.applied.is_empty()
+    }
+}
+
+/// Upgrades every collection in `store` to the current schema. With
+/// `dry_run` set nothing is written and the reports describe what would
+/// change.
+pub fn migrate_store<S: Storage>(store: &S, dry_run: bool) -> Result<Vec<MigrationReport>> {
+    let mut keys = store.list_keys()?;
+    keys.sort();
+
+    let mut reports = Vec::new();
+    for key in keys {
+        let Some(original) = store.load_raw(&key)? else {
+            continue;
+        };
+        if !is_collection(&original) {
+            continue;
+        }
+
+        let mut upgraded = original.clone();
+        let applied = upgrade(&key, &mut upgraded)?;
+        if !applied.is_empty() && !dry_run {
+            // Loading through the store applies the same migrations; saving
+            // under its lock persists them.
+            store.update(&key, |_: &mut Value| Ok(()))?;
+        }
+
+        reports.push(MigrationReport {
+            from_version: schema_version(&original),
+            to_version: schema_version(&upgraded),
+            applied: applied.iter().map(|m| m.description).collect(),
+            changes: diff(&original, &upgraded),
+            key,
+        });
+    }
+    Ok(reports)
+}
@@ -1,23 +1,128 @@
-use anyhow::Result;
+use crate::storage::error::Result;
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::path::Path;
 
+pub mod archive;
+pub mod backend;
+pub mod error;
 pub mod json_store;
+pub mod memory_store;
+pub mod migrations;
 pub mod repository;
+pub mod resolver;
+pub mod sqlite_store;
 
+pub use backend::StorageBackend;
+pub use error::{RepositoryError, StorageError};
 pub use json_store::JsonStore;
+pub use memory_store::InMemoryStore;
 pub use repository::TeamRepository;
+pub use sqlite_store::SqliteStore;
+
+use json_store::DataCollection;
 
 pub trait Storage {
     fn save<T: serde::Serialize>(&self, key: &str, data: &T) -> Result<()>;
     fn load<T: for<'de> serde::Deserialize<'de>>(&self, key: &str) -> Result<Option<T>>;
     fn delete(&self, key: &str) -> Result<()>;
     fn list_keys(&self) -> Result<Vec<String>>;
+    
+    /// The value stored under `key` as persisted, before any schema
+    /// migrations are applied.
+    fn load_raw(&self, key: &str) -> Result<Option<serde_json::Value>> {
+        self.load(key)
+    }
+    
+    /// Runs a load-modify-save cycle on `key` as one transaction. Stores that
+    /// can be shared between processes override this to hold a lock for the
+    /// duration of the cycle.
+    fn update<T, R, F>(&self, key: &str, f: F) -> Result<R>
+    where
+        T: serde::Serialize + for<'de> serde::Deserialize<'de> + Default,
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        let mut data: T = self.load(key)?.unwrap_or_default();
+        let result = f(&mut data)?;
+        self.save(key, &data)?;
+        Ok(result)
+    }
+    
+    // Item-level access to `DataCollection`s. The defaults work on whole
+    // collections; stores with indexes override them to touch single records.
+    
+    fn put_item<T>(&self, collection: &str, id: &str, item: &T) -> Result<()>
+    where
+        T: Serialize + DeserializeOwned + Clone,
+    {
+        self.update(collection, |items: &mut DataCollection<T>| {
+            items.insert(id.to_string(), item.clone());
+            Ok(())
+        })
+    }
+    
+    fn get_item<T: DeserializeOwned>(&self, collection: &str, id: &str) -> Result<Option<T>> {
+        let items: Option<DataCollection<T>> = self.load(collection)?;
+        Ok(items.and_then(|mut items| items.items.remove(id)))
+    }
+    
+    fn remove_item<T>(&self, collection: &str, id: &str) -> Result<Option<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.update(collection, |items: &mut DataCollection<T>| Ok(items.remove(id)))
+    }
+    
+    fn list_items<T: DeserializeOwned>(&self, collection: &str) -> Result<Vec<T>> {
+        let items: Option<DataCollection<T>> = self.load(collection)?;
+        Ok(items.map(|items| items.items.into_values().collect()).unwrap_or_default())
+    }
+    
+    /// Items whose top-level string `field` equals `value`.
+    fn find_items<T: DeserializeOwned>(&self, collection: &str, field: &str, value: &str) -> Result<Vec<T>> {
+        let items: Option<DataCollection<serde_json::Value>> = self.load(collection)?;
+        items
+            .map(|items| items.items.into_values()
+                .filter(|item| item.get(field).and_then(|v| v.as_str()) == Some(value))
+                .map(serde_json::from_value)
+                .collect::<Result<Vec<T>, _>>())
+            .transpose()
+            .map(|items| items.unwrap_or_default())
+            .map_err(Into::into)
+    }
+    
+    /// Items whose top-level timestamp `field` lies within `[start, end]`,
+    /// oldest first.
+    fn find_items_in_range<T: DeserializeOwned>(
+        &self,
+        collection: &str,
+        field: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<T>> {
+        let items: Option<DataCollection<serde_json::Value>> = self.load(collection)?;
+        let mut matching: Vec<(DateTime<Utc>, serde_json::Value)> = items
+            .map(|items| items.items.into_values()
+                .filter_map(|item| {
+                    let at = item.get(field)?.as_str()?.parse::<DateTime<Utc>>().ok()?;
+                    (at >= start && at <= end).then_some((at, item))
+                })
+                .collect())
+            .unwrap_or_default();
+        
+        matching.sort_by_key(|(at, _)| *at);
+        matching
+            .into_iter()
+            .map(|(_, item)| serde_json::from_value(item).map_err(Into::into))
+            .collect()
+    }
 }
 
 pub fn ensure_data_directory(path: &str) -> Result<()> {
     let path = Path::new(path);
     if !path.exists() {
-        std::fs::create_dir_all(path)?;
+        std::fs::create_dir_all(path).map_err(|e| StorageError::io("create", path, e))?;
     }
     Ok(())
 }
\ No newline at end of file
@@ -1,176 +1,336 @@
 use crate::models::*;
-use crate::storage::{JsonStore, Storage};
-use crate::storage::json_store::DataCollection;
-use anyhow::Result;
+use crate::storage::{resolver, DataCollection, JsonStore, RepositoryError, Storage, StorageError};
+use chrono::{DateTime, NaiveDate, Utc};
+use std::path::Path;
 use uuid::Uuid;
 
-pub struct TeamRepository {
-    store: JsonStore,
+type Result<T, E = RepositoryError> = std::result::Result<T, E>;
+
+pub struct TeamRepository<S: Storage = JsonStore> {
+    store: S,
 }
 
-impl TeamRepository {
-    pub fn new(data_dir: &str) -> Result<Self> {
+impl TeamRepository<JsonStore> {
+    pub fn new<P: AsRef<Path>>(data_dir: P) -> Result<Self> {
         let store = JsonStore::new(data_dir)?;
         Ok(TeamRepository { store })
     }
-    
+}
+
+impl<S: Storage> TeamRepository<S> {
+    pub fn with_store(store: S) -> Self {
+        TeamRepository { store }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
     // Team member operations
+    /// Validates the member and rejects emails or Slack IDs already used by
+    /// someone else. Failures are returned as `MemberError`. The uniqueness
+    /// check and the write happen in one `update`, so concurrent saves cannot
+    /// both claim the same email.
     pub fn save_team_member(&self, member: &TeamMember) -> Result<()> {
-        let mut members: DataCollection<TeamMember> = self.store
-            .load("team_members")?
-            .unwrap_or_default();
-        
-        members.insert(member.id.to_string(), member.clone());
-        self.store.save("team_members", &members)?;
-        Ok(())
+        member.validate()?;
+        let saved = self.store.update("team_members", |members: &mut DataCollection<TeamMember>| {
+            member.check_unique(members.items.values()).map_err(|source| StorageError::InvalidMember {
+                id: member.id.to_string(),
+                name: member.name.clone(),
+                source,
+            })?;
+            members.insert(member.id.to_string(), member.clone());
+            Ok(())
+        });
+        match saved {
+            Err(StorageError::InvalidMember { source, .. }) => Err(source.into()),
+            saved => Ok(saved?),
+        }
     }
-    
+
     pub fn get_team_member(&self, id: Uuid) -> Result<Option<TeamMember>> {
-        let members: Option<DataCollection<TeamMember>> = self.store.load("team_members")?;
-        Ok(members.and_then(|m| m.get(&id.to_string()).cloned()))
+        Ok(self.store.get_item("team_members", &id.to_string())?)
     }
-    
+
     pub fn list_team_members(&self) -> Result<Vec<TeamMember>> {
-        let members: Option<DataCollection<TeamMember>> = self.store.load("team_members")?;
-        Ok(members.map(|m| m.items.into_values().collect()).unwrap_or_default())
+        Ok(self.store.list_items("team_members")?)
     }
-    
+
+    /// Finds a member by id, id prefix, email, Slack ID or name.
+    pub fn resolve_member(&self, query: &str) -> Result<TeamMember> {
+        let members = self.list_team_members()?;
+        Ok(resolver::resolve_member(&members, query)?.clone())
+    }
+
+    /// Finds a member by id, unique id prefix or email only.
+    pub fn resolve_member_exact(&self, query: &str) -> Result<TeamMember> {
+        let members = self.list_team_members()?;
+        Ok(resolver::resolve_member_exact(&members, query)?.clone())
+    }
+
+    /// Removes a member together with their project assignments.
     pub fn remove_team_member(&self, id: Uuid) -> Result<Option<TeamMember>> {
-        let mut members: DataCollection<TeamMember> = self.store
-            .load("team_members")?
-            .unwrap_or_default();
-        
-        let removed = members.remove(&id.to_string());
-        self.store.save("team_members", &members)?;
-        Ok(removed)
-    }
-    
+        for assignment in self.get_assignments_for_member(id)? {
+            self.remove_assignment(assignment.id)?;
+        }
+        Ok(self.store.remove_item("team_members", &id.to_string())?)
+    }
+
     // Project operations
     pub fn save_project(&self, project: &Project) -> Result<()> {
-        let mut projects: DataCollection<Project> = self.store
-            .load("projects")?
-            .unwrap_or_default();
-        
-        projects.insert(project.id.to_string(), project.clone());
-        self.store.save("projects", &projects)?;
-        Ok(())
+        Ok(self.store.put_item("projects", &project.id.to_string(), project)?)
     }
-    
+
     pub fn get_project(&self, id: Uuid) -> Result<Option<Project>> {
-        let projects: Option<DataCollection<Project>> = self.store.load("projects")?;
-        Ok(projects.and_then(|p| p.get(&id.to_string()).cloned()))
+        Ok(self.store.get_item("projects", &id.to_string())?)
     }
-    
+
     pub fn list_projects(&self) -> Result<Vec<Project>> {
-        let projects: Option<DataCollection<Project>> = self.store.load("projects")?;
-        Ok(projects.map(|p| p.items.into_values().collect()).unwrap_or_default())
+        Ok(self.store.list_items("projects")?)
     }
-    
+
+    // Assignment operations
+    pub fn save_assignment(&self, assignment: &Assignment) -> Result<()> {
+        assignment.validate().map_err(|e| RepositoryError::InvalidAssignment(e.to_string()))?;
+        Ok(self.store.put_item("assignments", &assignment.id.to_string(), assignment)?)
+    }
+
+    pub fn list_assignments(&self) -> Result<Vec<Assignment>> {
+        Ok(self.store.list_items("assignments")?)
+    }
+
+    pub fn remove_assignment(&self, id: Uuid) -> Result<Option<Assignment>> {
+        Ok(self.store.remove_item("assignments", &id.to_string())?)
+    }
+
+    /// Takes the member off the project from `date` on. Current assignments
+    /// end the day before and are kept as history; ones starting on or after
+    /// `date` are removed. Returns how many were ended or removed.
+    pub fn end_assignments(&self, member_id: Uuid, project_id: Uuid, date: NaiveDate) -> Result<usize> {
+        let last_day = date.pred_opt().unwrap_or(NaiveDate::MIN);
+        let mut changed = 0;
+        for mut assignment in self.get_assignments_for_member(member_id)? {
+            if assignment.project_id != project_id || assignment.end_date.is_some_and(|end| end <= last_day) {
+                continue;
+            }
+            if assignment.start_date >= date {
+                self.remove_assignment(assignment.id)?;
+            } else {
+                assignment.end_date = Some(last_day);
+                assignment.updated_at = Utc::now();
+                self.save_assignment(&assignment)?;
+            }
+            changed += 1;
+        }
+        Ok(changed)
+    }
+
+    pub fn get_assignments_for_member(&self, member_id: Uuid) -> Result<Vec<Assignment>> {
+        Ok(self.store.find_items("assignments", "member_id", &member_id.to_string())?)
+    }
+
+    pub fn get_assignments_for_project(&self, project_id: Uuid) -> Result<Vec<Assignment>> {
+        Ok(self.store.find_items("assignments", "project_id", &project_id.to_string())?)
+    }
+
+    /// Projects the member is assigned to on `date`, largest allocation first.
+    pub fn get_projects_for_member(&self, member_id: Uuid, date: NaiveDate) -> Result<Vec<(Project, Assignment)>> {
+        let mut assignments = self.get_assignments_for_member(member_id)?;
+        assignments.retain(|a| a.is_active_on(date));
+        assignments.sort_by_key(|a| std::cmp::Reverse(a.allocation));
+
+        let mut projects = Vec::new();
+        for assignment in assignments {
+            if let Some(project) = self.get_project(assignment.project_id)? {
+                projects.push((project, assignment));
+            }
+        }
+        Ok(projects)
+    }
+
+    /// Members assigned to the project on `date`, largest allocation first.
+    pub fn get_members_on_project(&self, project_id: Uuid, date: NaiveDate) -> Result<Vec<(TeamMember, Assignment)>> {
+        let mut assignments = self.get_assignments_for_project(project_id)?;
+        assignments.retain(|a| a.is_active_on(date));
+        assignments.sort_by_key(|a| std::cmp::Reverse(a.allocation));
+
+        let mut members = Vec::new();
+        for assignment in assignments {
+            if let Some(member) = self.get_team_member(assignment.member_id)? {
+                members.push((member, assignment));
+            }
+        }
+        Ok(members)
+    }
+
     // Status update operations
+    /// Saves the update and records the blockers it reports.
     pub fn save_status_update(&self, update: &StatusUpdate) -> Result<()> {
-        let mut updates: DataCollection<StatusUpdate> = self.store
-            .load("status_updates")?
-            .unwrap_or_default();
-        
-        updates.insert(update.id.to_string(), update.clone());
-        self.store.save("status_updates", &updates)?;
+        self.store.put_item("status_updates", &update.id.to_string(), update)?;
+        self.record_blockers(update)?;
         Ok(())
     }
-    
+
     pub fn get_status_updates_for_member(&self, member_id: Uuid) -> Result<Vec<StatusUpdate>> {
-        let updates: Option<DataCollection<StatusUpdate>> = self.store.load("status_updates")?;
-        Ok(updates
-            .map(|u| u.items.into_values()
-                .filter(|update| update.member_id == member_id)
-                .collect())
-            .unwrap_or_default())
-    }
-    
+        Ok(self.store.find_items("status_updates", "member_id", &member_id.to_string())?)
+    }
+
+    /// Status updates posted within `[start, end]`, oldest first.
+    pub fn get_status_updates_in_range(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<StatusUpdate>> {
+        Ok(self.store.find_items_in_range("status_updates", "timestamp", start, end)?)
+    }
+
     pub fn get_recent_status_updates(&self, limit: usize) -> Result<Vec<StatusUpdate>> {
-        let updates: Option<DataCollection<StatusUpdate>> = self.store.load("status_updates")?;
-        let mut all_updates: Vec<StatusUpdate> = updates
-            .map(|u| u.items.into_values().collect())
-            .unwrap_or_default();
-        
+        let mut all_updates: Vec<StatusUpdate> = self.store.list_items("status_updates")?;
+
         // Sort by timestamp descending
-        all_updates.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        all_updates.sort_by_key(|update| std::cmp::Reverse(update.timestamp));
         all_updates.truncate(limit);
         Ok(all_updates)
     }
-    
+
+    // Blocker operations
+    pub fn save_blocker(&self, blocker: &Blocker) -> Result<()> {
+        Ok(self.store.put_item("blockers", &blocker.id.to_string(), blocker)?)
+    }
+
+    pub fn get_blocker(&self, id: Uuid) -> Result<Option<Blocker>> {
+        Ok(self.store.get_item("blockers", &id.to_string())?)
+    }
+
+    /// Every blocker, oldest first.
+    pub fn list_blockers(&self) -> Result<Vec<Blocker>> {
+        let mut blockers: Vec<Blocker> = self.store.list_items("blockers")?;
+        blockers.sort_by_key(|b| b.opened_at);
+        Ok(blockers)
+    }
+
+    pub fn list_open_blockers(&self) -> Result<Vec<Blocker>> {
+        let mut blockers = self.list_blockers()?;
+        blockers.retain(Blocker::is_open);
+        Ok(blockers)
+    }
+
+    /// Applies `change` to the blocker as it is stored now, in one `update`,
+    /// so reports recorded since it was read are kept. Returns the changed
+    /// blocker, or `None` when there is no such blocker.
+    pub fn update_blocker(&self, id: Uuid, change: impl FnOnce(&mut Blocker)) -> Result<Option<Blocker>> {
+        Ok(self.store.update("blockers", |blockers: &mut DataCollection<Blocker>| {
+            let key = id.to_string();
+            let Some(mut blocker) = blockers.get(&key).cloned() else {
+                return Ok(None);
+            };
+            change(&mut blocker);
+            blockers.insert(key, blocker.clone());
+            Ok(Some(blocker))
+        })?)
+    }
+
+    /// Marks the blocker resolved unless it already is; see `update_blocker`.
+    pub fn resolve_blocker(&self, id: Uuid, at: DateTime<Utc>) -> Result<Option<Blocker>> {
+        self.update_blocker(id, |blocker| {
+            if blocker.is_open() {
+                blocker.resolve(at);
+            }
+        })
+    }
+
+    /// Matches the update's blockers against the open ones. A blocker the
+    /// member already reported is carried forward, one someone else reported
+    /// gains the member as a reporter, and anything new is opened against
+    /// the member's main project. Matching and saving happen in one
+    /// `update`, so two updates reporting the same blocker cannot both open
+    /// it. Returns the blockers touched.
+    pub fn record_blockers(&self, update: &StatusUpdate) -> Result<Vec<Blocker>> {
+        if !update.has_blockers() {
+            return Ok(Vec::new());
+        }
+        let project_id = self.get_projects_for_member(update.member_id, update.timestamp.date_naive())?
+            .first()
+            .map(|(project, _)| project.id);
+
+        Ok(self.store.update("blockers", |blockers: &mut DataCollection<Blocker>| {
+            let mut touched: Vec<Blocker> = Vec::new();
+            for text in &update.blockers {
+                if text.trim().is_empty() || touched.iter().any(|b| b.matches(text)) {
+                    continue;
+                }
+                // The member's own report first, then the oldest anyone made
+                let mut open: Vec<&Blocker> = blockers.items.values().filter(|b| b.is_open() && b.matches(text)).collect();
+                open.sort_by_key(|b| (!b.is_reported_by(update.member_id), b.opened_at));
+
+                let blocker = match open.first() {
+                    Some(&existing) => {
+                        let mut blocker = existing.clone();
+                        if !blocker.is_reported_by(update.member_id) {
+                            blocker.also_reported_by.push(update.member_id);
+                        }
+                        blocker.last_reported_at = blocker.last_reported_at.max(update.timestamp);
+                        blocker
+                    }
+                    None => {
+                        let mut blocker = Blocker::from_report(text, update.member_id, update.timestamp);
+                        blocker.project_id = project_id;
+                        blocker
+                    }
+                };
+                blockers.insert(blocker.id.to_string(), blocker.clone());
+                touched.push(blocker);
+            }
+            Ok(touched)
+        })?)
+    }
+
+    // Escalation operations
+    pub fn save_escalation(&self, escalation: &Escalation) -> Result<()> {
+        Ok(self.store.put_item("escalations", &escalation.id.to_string(), escalation)?)
+    }
+
+    pub fn get_escalations_for_blocker(&self, blocker_id: Uuid) -> Result<Vec<Escalation>> {
+        Ok(self.store.find_items("escalations", "blocker_id", &blocker_id.to_string())?)
+    }
+
     // Conversation operations
     pub fn save_conversation(&self, conversation: &Conversation) -> Result<()> {
-        let mut conversations: DataCollection<Conversation> = self.store
-            .load("conversations")?
-            .unwrap_or_default();
-        
-        conversations.insert(conversation.id.to_string(), conversation.clone());
-        self.store.save("conversations", &conversations)?;
-        Ok(())
+        Ok(self.store.put_item("conversations", &conversation.id.to_string(), conversation)?)
     }
-    
+
     pub fn get_conversation(&self, id: Uuid) -> Result<Option<Conversation>> {
-        let conversations: Option<DataCollection<Conversation>> = self.store.load("conversations")?;
-        Ok(conversations.and_then(|c| c.get(&id.to_string()).cloned()))
+        Ok(self.store.get_item("conversations", &id.to_string())?)
     }
-    
+
     pub fn get_conversations_for_member(&self, member_id: Uuid) -> Result<Vec<Conversation>> {
-        let conversations: Option<DataCollection<Conversation>> = self.store.load("conversations")?;
-        Ok(conversations
-            .map(|c| c.items.into_values()
-                .filter(|conv| conv.member_id == member_id)
-                .collect())
-            .unwrap_or_default())
-    }
-    
+        Ok(self.store.find_items("conversations", "member_id", &member_id.to_string())?)
+    }
+
     // AI Decision operations
     pub fn save_ai_decision(&self, decision: &AIDecision) -> Result<()> {
-        let mut decisions: DataCollection<AIDecision> = self.store
-            .load("ai_decisions")?
-            .unwrap_or_default();
-        
-        decisions.insert(decision.id.to_string(), decision.clone());
-        self.store.save("ai_decisions", &decisions)?;
-        Ok(())
+        Ok(self.store.put_item("ai_decisions", &decision.id.to_string(), decision)?)
     }
-    
+
     pub fn get_recent_ai_decisions(&self, limit: usize) -> Result<Vec<AIDecision>> {
-        let decisions: Option<DataCollection<AIDecision>> = self.store.load("ai_decisions")?;
-        let mut all_decisions: Vec<AIDecision> = decisions
-            .map(|d| d.items.into_values().collect())
-            .unwrap_or_default();
-        
+        let mut all_decisions: Vec<AIDecision> = self.store.list_items("ai_decisions")?;
+
         // Sort by timestamp descending
-        all_decisions.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        all_decisions.sort_by_key(|decision| std::cmp::Reverse(decision.created_at));
         all_decisions.truncate(limit);
         Ok(all_decisions)
     }
-    
+
     // Team metrics operations
     pub fn save_team_metrics(&self, metrics: &TeamMetrics) -> Result<()> {
-        let mut all_metrics: DataCollection<TeamMetrics> = self.store
-            .load("team_metrics")?
-            .unwrap_or_default();
-        
-        all_metrics.insert(metrics.id.to_string(), metrics.clone());
-        self.store.save("team_metrics", &all_metrics)?;
-        Ok(())
+        Ok(self.store.put_item("team_metrics", &metrics.id.to_string(), metrics)?)
     }
-    
+
     pub fn get_latest_team_metrics(&self) -> Result<Option<TeamMetrics>> {
-        let metrics: Option<DataCollection<TeamMetrics>> = self.store.load("team_metrics")?;
-        Ok(metrics.and_then(|m| {
-            m.items.into_values()
-                .max_by_key(|metrics| metrics.date)
-        }))
-    }
-    
-    pub fn get_team_metrics_range(&self, start_date: chrono::DateTime<chrono::Utc>, end_date: chrono::DateTime<chrono::Utc>) -> Result<Vec<TeamMetrics>> {
-        let metrics: Option<DataCollection<TeamMetrics>> = self.store.load("team_metrics")?;
-        Ok(metrics
-            .map(|m| m.items.into_values()
-                .filter(|metric| metric.date >= start_date && metric.date <= end_date)
-                .collect())
-            .unwrap_or_default())
-    }
-}
\ No newline at end of file
+        let metrics: Vec<TeamMetrics> = self.store.list_items("team_metrics")?;
+        Ok(metrics.into_iter().max_by_key(|metrics| metrics.date))
+    }
+
+    /// Metrics dated within the range, oldest first.
+    pub fn get_team_metrics_range(&self, start_date: DateTime<Utc>, end_date: DateTime<Utc>) -> Result<Vec<TeamMetrics>> {
+        let mut metrics: Vec<TeamMetrics> = self.store.find_items_in_range("team_metrics", "date", start_date, end_date)?;
+        metrics.sort_by_key(|m| m.date);
+        Ok(metrics)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::models::TeamMember;
+use std::fmt;
+use uuid::Uuid;
+
+/// Shortest UUID prefix accepted as a member reference.
+pub const MIN_ID_PREFIX: usize = 4;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResolveError {
+    #[error("no team member matching {0:?}")]
+    NotFound(String),
+    #[error("{query:?} matches {} team members:\n{}\nuse an email or id to pick one", .candidates.len(), format_candidates(.candidates))]
+    Ambiguous { query: String, candidates: Vec<Candidate> },
+}
+
+/// A member that matched an ambiguous query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub id: Uuid,
+    pub name: String,
+    pub email: String,
+}
+
+impl fmt::Display for Candidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let id = self.id.to_string();
+        write!(f, "{} <{}> ({})", self.name, self.email, &id[..8])
+    }
+}
+
+fn format_candidates(candidates: &[Candidate]) -> String {
+    candidates
+        .iter()
+        .map(|c| format!("  - {}", c))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Finds the member `query` refers to. Tried in order, stopping at the first
+/// kind of match that finds anyone: full UUID, email, Slack ID, UUID prefix,
+/// exact name, then a fuzzy name match that tolerates partial names and
+/// small typos.
+pub fn resolve_member<'a>(members: &'a [TeamMember], query: &str) -> Result<&'a TeamMember, ResolveError> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Err(ResolveError::NotFound(query.to_string()));
+    }
+
+    if let Ok(id) = Uuid::parse_str(query) {
+        return members
+            .iter()
+            .find(|m| m.id == id)
+            .ok_or_else(|| ResolveError::NotFound(query.to_string()));
+    }
+
+    let lower = query.to_lowercase();
+    let slack = lower.trim_start_matches('@');
+    let strategies: [&dyn Fn(&TeamMember) -> bool; 4] = [
+        &|m| m.email.eq_ignore_ascii_case(query),
+        &|m| m.slack_id.as_deref().is_some_and(|id| id.trim_start_matches('@').eq_ignore_ascii_case(slack)),
+        &|m| is_id_prefix(&lower) && m.id.to_string().starts_with(&lower),
+        &|m| m.name.eq_ignore_ascii_case(query),
+    ];
+    for matches in strategies {
+        let found: Vec<&TeamMember> = members.iter().filter(|m| matches(m)).collect();
+        if !found.is_empty() {
+            return single(query, found);
+        }
+    }
+
+    let partial: Vec<&TeamMember> = members.iter().filter(|m| name_contains(&m.name, &lower)).collect();
+    if !partial.is_empty() {
+        return single(query, partial);
+    }
+
+    // Typos: the closest names within a small edit distance
+    let max_distance = (lower.chars().count() / 4).max(1);
+    let scored: Vec<(usize, &TeamMember)> = members
+        .iter()
+        .map(|m| (name_distance(&m.name, &lower), m))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .collect();
+    let best = scored.iter().map(|(distance, _)| *distance).min();
+    let closest = scored.into_iter().filter(|(d, _)| Some(*d) == best).map(|(_, m)| m).collect();
+    single(query, closest)
+}
+
+/// Like `resolve_member` but only accepts identifiers that cannot be a
+/// near miss: a full UUID, an email or a unique UUID prefix. For commands
+/// that destroy data.
+pub fn resolve_member_exact<'a>(members: &'a [TeamMember], query: &str) -> Result<&'a TeamMember, ResolveError> {
+    let query = query.trim();
+    let lower = query.to_lowercase();
+    let found: Vec<&TeamMember> = match Uuid::parse_str(query) {
+        Ok(id) => members.iter().filter(|m| m.id == id).collect(),
+        Err(_) => members
+            .iter()
+            .filter(|m| m.email.eq_ignore_ascii_case(query) || (is_id_prefix(&lower) && m.id.to_string().starts_with(&lower)))
+            .collect(),
+    };
+    single(query, found)
+}
+
+fn single<'a>(query: &str, mut found: Vec<&'a TeamMember>) -> Result<&'a TeamMember, ResolveError> {
+    match found.len() {
+        0 => Err(ResolveError::NotFound(query.to_string())),
+        1 => Ok(found.remove(0)),
+        _ => {
+            found.sort_by_key(|m| m.name.to_lowercase());
+            Err(ResolveError::Ambiguous {
+                query: query.to_string(),
+                candidates: found
+                    .into_iter()
+                    .map(|m| Candidate { id: m.id, name: m.name.clone(), email: m.email.clone() })
+                    .collect(),
+            })
+        }
+    }
+}
+
+fn is_id_prefix(query: &str) -> bool {
+    query.len() >= MIN_ID_PREFIX && query.chars().all(|c| c.is_ascii_hexdigit() || c == '-')
+}
+
+/// Every word of the query starts a word of the name, so "jo do" and
+/// "john" both match "John Doe".
+fn name_contains(name: &str, query: &str) -> bool {
+    let name = name.to_lowercase();
+    let words: Vec<&str> = name.split_whitespace().collect();
+    query.split_whitespace().all(|q| words.iter().any(|w| w.starts_with(q)))
+}
+
+/// Edit distance from the query to the full name or its closest single word.
+fn name_distance(name: &str, query: &str) -> usize {
+    let name = name.to_lowercase();
+    std::iter::once(name.as_str())
+        .chain(name.split_whitespace())
+        .map(|candidate| levenshtein(candidate, query))
+        .min()
+        .unwrap_or(usize::MAX)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
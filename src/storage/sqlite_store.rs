@@ -0,0 +1,547 @@
+use crate::storage::{Storage, StorageError};
+use crate::storage::error::Result;
+use crate::storage::json_store::DEFAULT_LOCK_TIMEOUT;
+use crate::storage::migrations::{self, CURRENT_SCHEMA_VERSION};
+use chrono::{DateTime, Utc};
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, TransactionBehavior};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+pub const DATABASE_FILE: &str = "timeless.db";
+
+#[derive(Clone, Copy)]
+enum ColumnKind {
+    Text,
+    Integer,
+    Real,
+    /// RFC 3339 string in JSON, stored as microseconds since the epoch so
+    /// that range queries and ordering work on the index.
+    Timestamp,
+}
+
+/// A column mirrored from a top-level field of the stored JSON record.
+struct Column {
+    name: &'static str,
+    kind: ColumnKind,
+}
+
+const fn column(name: &'static str, kind: ColumnKind) -> Column {
+    Column { name, kind }
+}
+
+struct Table<'a> {
+    name: &'a str,
+    columns: &'a [Column],
+    indexes: &'a [&'a [&'a str]],
+}
+
+use ColumnKind::*;
+
+const TABLES: &[Table<'static>] = &[
+    Table {
+        name: "team_members",
+        columns: &[
+            column("name", Text),
+            column("email", Text),
+            column("slack_id", Text),
+            column("role", Text),
+            column("created_at", Timestamp),
+            column("updated_at", Timestamp),
+        ],
+        indexes: &[&["email"], &["slack_id"]],
+    },
+    Table {
+        name: "projects",
+        columns: &[
+            column("name", Text),
+            column("description", Text),
+            column("status", Text),
+            column("created_at", Timestamp),
+            column("updated_at", Timestamp),
+        ],
+        indexes: &[&["status"]],
+    },
+    Table {
+        name: "assignments",
+        columns: &[
+            column("member_id", Text),
+            column("project_id", Text),
+            column("allocation", Integer),
+            column("start_date", Text),
+            column("end_date", Text),
+        ],
+        indexes: &[&["member_id"], &["project_id"]],
+    },
+    Table {
+        name: "status_updates",
+        columns: &[
+            column("member_id", Text),
+            column("content", Text),
+            column("timestamp", Timestamp),
+            column("mood", Text),
+        ],
+        indexes: &[&["member_id", "timestamp"], &["timestamp"]],
+    },
+    Table {
+        name: "blockers",
+        columns: &[
+            column("reporter_id", Text),
+            column("severity", Text),
+            column("status", Text),
+            column("opened_at", Timestamp),
+            column("resolved_at", Timestamp),
+        ],
+        indexes: &[&["status"], &["reporter_id"]],
+    },
+    Table {
+        name: "escalations",
+        columns: &[
+            column("blocker_id", Text),
+            column("sent_at", Timestamp),
+        ],
+        indexes: &[&["blocker_id"]],
+    },
+    Table {
+        name: "conversations",
+        columns: &[
+            column("member_id", Text),
+            column("created_at", Timestamp),
+            column("updated_at", Timestamp),
+        ],
+        indexes: &[&["member_id"]],
+    },
+    Table {
+        name: "ai_decisions",
+        columns: &[
+            column("decision_type", Text),
+            column("context", Text),
+            column("recommendation", Text),
+            column("confidence", Real),
+            column("created_at", Timestamp),
+            column("outcome", Text),
+        ],
+        indexes: &[&["created_at"]],
+    },
+    Table {
+        name: "team_metrics",
+        columns: &[
+            column("date", Timestamp),
+            column("active_members", Integer),
+            column("completed_tasks", Integer),
+            column("blockers_count", Integer),
+            column("average_satisfaction", Real),
+            column("velocity", Real),
+        ],
+        indexes: &[&["date"]],
+    },
+];
+
+/// Storage backed by a single SQLite database in the data directory. Each
+/// `DataCollection` key maps to a table with one row per item; values that
+/// are not collections are kept as JSON documents.
+pub struct SqliteStore {
+    conn: Mutex<Connection>,
+}
+
+impl SqliteStore {
+    pub fn new<P: AsRef<Path>>(data_dir: P) -> Result<Self> {
+        Self::open(data_dir, DEFAULT_LOCK_TIMEOUT)
+    }
+
+    pub fn open<P: AsRef<Path>>(data_dir: P, lock_timeout: Duration) -> Result<Self> {
+        let data_dir = data_dir.as_ref();
+        if !data_dir.exists() {
+            std::fs::create_dir_all(data_dir).map_err(|e| StorageError::io("create", data_dir, e))?;
+        }
+
+        let path = data_dir.join(DATABASE_FILE);
+        let conn = Connection::open(&path)?;
+        conn.busy_timeout(lock_timeout)?;
+        conn.pragma_update(None, "journal_mode", "WAL")?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS collections (
+                 name TEXT PRIMARY KEY,
+                 meta TEXT NOT NULL
+             );
+             CREATE TABLE IF NOT EXISTS documents (
+                 key TEXT PRIMARY KEY,
+                 data TEXT NOT NULL
+             );",
+        )?;
+        for table in TABLES {
+            create_table(&conn, table)?;
+        }
+
+        Ok(SqliteStore { conn: Mutex::new(conn) })
+    }
+
+    fn connection(&self) -> Result<MutexGuard<'_, Connection>> {
+        self.conn.lock().map_err(|_| StorageError::Poisoned("SQLite connection"))
+    }
+}
+
+fn table_for(collection: &str) -> Result<Table<'_>> {
+    if let Some(table) = TABLES.iter().find(|t| t.name == collection) {
+        return Ok(Table { name: table.name, columns: table.columns, indexes: table.indexes });
+    }
+
+    // Collections without a dedicated schema get a plain id/data table
+    if collection.is_empty() || !collection.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(StorageError::InvalidKey(format!("invalid collection name {:?}", collection)));
+    }
+    Ok(Table { name: collection, columns: &[], indexes: &[] })
+}
+
+fn create_table(conn: &Connection, table: &Table) -> Result<()> {
+    let columns: String = table.columns
+        .iter()
+        .map(|c| format!("{} {}, ", c.name, sql_type(c.kind)))
+        .collect();
+    conn.execute_batch(&format!(
+        "CREATE TABLE IF NOT EXISTS {} (id TEXT PRIMARY KEY, {}data TEXT NOT NULL);",
+        table.name, columns
+    ))?;
+
+    for index in table.indexes {
+        conn.execute_batch(&format!(
+            "CREATE INDEX IF NOT EXISTS idx_{}_{} ON {} ({});",
+            table.name,
+            index.join("_"),
+            table.name,
+            index.join(", ")
+        ))?;
+    }
+    Ok(())
+}
+
+fn sql_type(kind: ColumnKind) -> &'static str {
+    match kind {
+        Text => "TEXT",
+        Integer | Timestamp => "INTEGER",
+        Real => "REAL",
+    }
+}
+
+fn column_value(item: &Value, column: &Column) -> SqlValue {
+    let Some(value) = item.get(column.name) else {
+        return SqlValue::Null;
+    };
+
+    match (column.kind, value) {
+        (_, Value::Null) => SqlValue::Null,
+        (Text, Value::String(s)) => SqlValue::Text(s.clone()),
+        (Text, other) => SqlValue::Text(other.to_string()),
+        (Integer, v) => v.as_i64().map_or(SqlValue::Null, SqlValue::Integer),
+        (Real, v) => v.as_f64().map_or(SqlValue::Null, SqlValue::Real),
+        (Timestamp, v) => v.as_str()
+            .and_then(|s| s.parse::<DateTime<Utc>>().ok())
+            .map_or(SqlValue::Null, |at| SqlValue::Integer(at.timestamp_micros())),
+    }
+}
+
+fn read_meta(conn: &Connection, collection: &str) -> Result<Option<Map<String, Value>>> {
+    let meta: Option<String> = conn
+        .query_row("SELECT meta FROM collections WHERE name = ?1", params![collection], |row| row.get(0))
+        .optional()?;
+    meta.map(|m| serde_json::from_str(&m).map_err(Into::into)).transpose()
+}
+
+fn write_meta(conn: &Connection, collection: &str, meta: &Map<String, Value>) -> Result<()> {
+    conn.execute(
+        "INSERT INTO collections (name, meta) VALUES (?1, ?2)
+         ON CONFLICT(name) DO UPDATE SET meta = excluded.meta",
+        params![collection, serde_json::to_string(meta)?],
+    )?;
+    Ok(())
+}
+
+/// Bumps `last_updated` on the collection, creating its metadata if needed.
+fn touch_collection(conn: &Connection, collection: &str) -> Result<()> {
+    let mut meta = read_meta(conn, collection)?.unwrap_or_default();
+    meta.insert("last_updated".to_string(), serde_json::to_value(Utc::now())?);
+    meta.entry("schema_version").or_insert_with(|| Value::from(CURRENT_SCHEMA_VERSION));
+    write_meta(conn, collection, &meta)
+}
+
+fn upsert_row(conn: &Connection, table: &Table, id: &str, item: &Value) -> Result<()> {
+    let names: Vec<&str> = table.columns.iter().map(|c| c.name).collect();
+    let placeholders: Vec<String> = (1..=names.len() + 2).map(|i| format!("?{}", i)).collect();
+    let sql = format!(
+        "INSERT OR REPLACE INTO {} (id, {}data) VALUES ({})",
+        table.name,
+        names.iter().map(|n| format!("{}, ", n)).collect::<String>(),
+        placeholders.join(", ")
+    );
+
+    let mut values = vec![SqlValue::Text(id.to_string())];
+    values.extend(table.columns.iter().map(|c| column_value(item, c)));
+    values.push(SqlValue::Text(serde_json::to_string(item)?));
+    conn.execute(&sql, params_from_iter(values))?;
+    Ok(())
+}
+
+fn query_values(conn: &Connection, sql: &str, params: &[SqlValue]) -> Result<Vec<Value>> {
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map(params_from_iter(params.iter()), |row| row.get::<_, String>(0))?;
+    rows.map(|data| Ok(serde_json::from_str(&data?)?)).collect()
+}
+
+fn load_value(conn: &Connection, key: &str) -> Result<Option<Value>> {
+    if let Some(mut meta) = read_meta(conn, key)? {
+        let table = table_for(key)?;
+        create_table(conn, &table)?;
+
+        let mut stmt = conn.prepare(&format!("SELECT id, data FROM {}", table.name))?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))?;
+        let mut items = Map::new();
+        for row in rows {
+            let (id, data) = row?;
+            items.insert(id, serde_json::from_str(&data)?);
+        }
+
+        meta.insert("items".to_string(), Value::Object(items));
+        return Ok(Some(Value::Object(meta)));
+    }
+
+    let document: Option<String> = conn
+        .query_row("SELECT data FROM documents WHERE key = ?1", params![key], |row| row.get(0))
+        .optional()?;
+    document.map(|d| serde_json::from_str(&d).map_err(Into::into)).transpose()
+}
+
+fn save_value(conn: &Connection, key: &str, value: Value) -> Result<()> {
+    delete_key(conn, key)?;
+
+    let is_known_table = TABLES.iter().any(|t| t.name == key);
+    if !migrations::is_collection(&value) {
+        if is_known_table {
+            return Err(StorageError::InvalidKey(format!("{} must be saved as a collection of items", key)));
+        }
+        conn.execute(
+            "INSERT INTO documents (key, data) VALUES (?1, ?2)",
+            params![key, serde_json::to_string(&value)?],
+        )?;
+        return Ok(());
+    }
+
+    let Value::Object(mut meta) = value else {
+        unreachable!("collections are JSON objects");
+    };
+    let Some(Value::Object(items)) = meta.remove("items") else {
+        unreachable!("collections have an items object");
+    };
+
+    let table = table_for(key)?;
+    create_table(conn, &table)?;
+    for (id, item) in &items {
+        upsert_row(conn, &table, id, item)?;
+    }
+    write_meta(conn, key, &meta)
+}
+
+fn delete_key(conn: &Connection, key: &str) -> Result<()> {
+    if read_meta(conn, key)?.is_some() {
+        let table = table_for(key)?;
+        create_table(conn, &table)?;
+        conn.execute(&format!("DELETE FROM {}", table.name), [])?;
+        conn.execute("DELETE FROM collections WHERE name = ?1", params![key])?;
+    }
+    conn.execute("DELETE FROM documents WHERE key = ?1", params![key])?;
+    Ok(())
+}
+
+/// Runs pending migrations over rows read straight from a collection table,
+/// so item-level reads see the same shape as a full `load`.
+fn upgrade_items(conn: &Connection, collection: &str, values: Vec<Value>) -> Result<Vec<Value>> {
+    let version = read_meta(conn, collection)?
+        .map_or(CURRENT_SCHEMA_VERSION, |meta| migrations::schema_version(&Value::Object(meta)));
+    if version == CURRENT_SCHEMA_VERSION || values.is_empty() {
+        return Ok(values);
+    }
+
+    // Zero-padded keys keep the caller's row order through the map
+    let items: Map<String, Value> = values.into_iter().enumerate().map(|(i, v)| (format!("{:010}", i), v)).collect();
+    let mut data = serde_json::json!({ "schema_version": version, "items": items });
+    migrations::upgrade(collection, &mut data)?;
+    let Some(Value::Object(items)) = data.get_mut("items").map(Value::take) else {
+        unreachable!("upgrade keeps the items object");
+    };
+    Ok(items.into_iter().map(|(_, v)| v).collect())
+}
+
+fn from_values<T: DeserializeOwned>(values: Vec<Value>) -> Result<Vec<T>> {
+    values.into_iter().map(|v| serde_json::from_value(v).map_err(Into::into)).collect()
+}
+
+impl Storage for SqliteStore {
+    fn save<T: Serialize>(&self, key: &str, data: &T) -> Result<()> {
+        let value = serde_json::to_value(data)?;
+        let mut conn = self.connection()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        save_value(&tx, key, value)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn load<T: for<'de> Deserialize<'de>>(&self, key: &str) -> Result<Option<T>> {
+        let Some(mut data) = self.load_raw(key)? else {
+            return Ok(None);
+        };
+        migrations::upgrade(key, &mut data)?;
+        Ok(Some(serde_json::from_value(data)?))
+    }
+    
+    fn load_raw(&self, key: &str) -> Result<Option<Value>> {
+        let conn = self.connection()?;
+        load_value(&conn, key)
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        let mut conn = self.connection()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        delete_key(&tx, key)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn list_keys(&self) -> Result<Vec<String>> {
+        let conn = self.connection()?;
+        let mut stmt = conn.prepare("SELECT name FROM collections UNION SELECT key FROM documents")?;
+        let keys = stmt.query_map([], |row| row.get(0))?.collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(keys)
+    }
+
+    fn update<T, R, F>(&self, key: &str, f: F) -> Result<R>
+    where
+        T: Serialize + for<'de> Deserialize<'de> + Default,
+        F: FnOnce(&mut T) -> Result<R>,
+    {
+        let mut conn = self.connection()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        let mut data: T = match load_value(&tx, key)? {
+            Some(mut value) => {
+                migrations::upgrade(key, &mut value)?;
+                serde_json::from_value(value)?
+            }
+            None => T::default(),
+        };
+        let result = f(&mut data)?;
+        save_value(&tx, key, serde_json::to_value(&data)?)?;
+        tx.commit()?;
+        Ok(result)
+    }
+
+    fn put_item<T>(&self, collection: &str, id: &str, item: &T) -> Result<()>
+    where
+        T: Serialize + DeserializeOwned + Clone,
+    {
+        let table = table_for(collection)?;
+        let value = serde_json::to_value(item)?;
+        let mut conn = self.connection()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        create_table(&tx, &table)?;
+        upsert_row(&tx, &table, id, &value)?;
+        touch_collection(&tx, collection)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_item<T: DeserializeOwned>(&self, collection: &str, id: &str) -> Result<Option<T>> {
+        let table = table_for(collection)?;
+        let conn = self.connection()?;
+        create_table(&conn, &table)?;
+        let values = query_values(
+            &conn,
+            &format!("SELECT data FROM {} WHERE id = ?1", table.name),
+            &[SqlValue::Text(id.to_string())],
+        )?;
+        Ok(from_values(upgrade_items(&conn, collection, values)?)?.into_iter().next())
+    }
+
+    fn remove_item<T>(&self, collection: &str, id: &str) -> Result<Option<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let table = table_for(collection)?;
+        let mut conn = self.connection()?;
+        let tx = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+        create_table(&tx, &table)?;
+        let values = query_values(
+            &tx,
+            &format!("SELECT data FROM {} WHERE id = ?1", table.name),
+            &[SqlValue::Text(id.to_string())],
+        )?;
+        let removed = from_values(upgrade_items(&tx, collection, values)?)?.into_iter().next();
+        if removed.is_some() {
+            tx.execute(&format!("DELETE FROM {} WHERE id = ?1", table.name), params![id])?;
+            touch_collection(&tx, collection)?;
+        }
+        tx.commit()?;
+        Ok(removed)
+    }
+
+    fn list_items<T: DeserializeOwned>(&self, collection: &str) -> Result<Vec<T>> {
+        let table = table_for(collection)?;
+        let conn = self.connection()?;
+        create_table(&conn, &table)?;
+        let values = query_values(&conn, &format!("SELECT data FROM {}", table.name), &[])?;
+        from_values(upgrade_items(&conn, collection, values)?)
+    }
+
+    fn find_items<T: DeserializeOwned>(&self, collection: &str, field: &str, value: &str) -> Result<Vec<T>> {
+        let table = table_for(collection)?;
+        let conn = self.connection()?;
+        create_table(&conn, &table)?;
+
+        if table.columns.iter().any(|c| c.name == field && matches!(c.kind, Text)) {
+            let sql = format!("SELECT data FROM {} WHERE {} = ?1", table.name, field);
+            let values = query_values(&conn, &sql, &[SqlValue::Text(value.to_string())])?;
+            return from_values(upgrade_items(&conn, collection, values)?);
+        }
+
+        let values = query_values(&conn, &format!("SELECT data FROM {}", table.name), &[])?;
+        from_values(upgrade_items(&conn, collection, values)?
+            .into_iter()
+            .filter(|item| item.get(field).and_then(|v| v.as_str()) == Some(value))
+            .collect())
+    }
+
+    fn find_items_in_range<T: DeserializeOwned>(
+        &self,
+        collection: &str,
+        field: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<T>> {
+        let table = table_for(collection)?;
+        let conn = self.connection()?;
+        create_table(&conn, &table)?;
+
+        if table.columns.iter().any(|c| c.name == field && matches!(c.kind, Timestamp)) {
+            let sql = format!(
+                "SELECT data FROM {} WHERE {} BETWEEN ?1 AND ?2 ORDER BY {}",
+                table.name, field, field
+            );
+            let bounds = [SqlValue::Integer(start.timestamp_micros()), SqlValue::Integer(end.timestamp_micros())];
+            let values = query_values(&conn, &sql, &bounds)?;
+            return from_values(upgrade_items(&conn, collection, values)?);
+        }
+
+        let values = query_values(&conn, &format!("SELECT data FROM {}", table.name), &[])?;
+        let values = upgrade_items(&conn, collection, values)?;
+        let mut matching: Vec<(DateTime<Utc>, Value)> = values
+            .into_iter()
+            .filter_map(|item| {
+                let at = item.get(field)?.as_str()?.parse::<DateTime<Utc>>().ok()?;
+                (at >= start && at <= end).then_some((at, item))
+            })
+            .collect();
+        matching.sort_by_key(|(at, _)| *at);
+        from_values(matching.into_iter().map(|(_, item)| item).collect())
+    }
+}
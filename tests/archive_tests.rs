@@ -0,0 +1,94 @@
+use timeless::models::*;
+use timeless::storage::archive::{self, ImportMode, Resolution};
+use timeless::storage::{InMemoryStore, StorageError, TeamRepository};
+
+fn member(name: &str, email: &str) -> TeamMember {
+    TeamMember::new(name.to_string(), email.to_string(), "Developer".to_string())
+}
+
+#[test]
+fn test_export_and_import_into_empty_store() {
+    let source = TeamRepository::with_store(InMemoryStore::new());
+    source.save_team_member(&member("John Doe", "john@example.com")).unwrap();
+    source.save_project(&Project::new("Apollo".to_string(), "Moonshot".to_string())).unwrap();
+
+    let exported = archive::export(source.store()).unwrap();
+    assert_eq!(exported.collections.len(), 2);
+    assert_eq!(exported.item_count(), 2);
+
+    // The archive survives a trip through its on-disk JSON form
+    let json = serde_json::to_string(&exported).unwrap();
+    let restored = serde_json::from_str(&json).unwrap();
+
+    let target = TeamRepository::with_store(InMemoryStore::new());
+    let reports = archive::import(target.store(), restored, ImportMode::Merge, false).unwrap();
+    assert_eq!(reports.iter().map(|r| r.added).sum::<usize>(), 2);
+    assert_eq!(target.list_team_members().unwrap()[0].name, "John Doe");
+    assert_eq!(target.list_projects().unwrap()[0].name, "Apollo");
+}
+
+#[test]
+fn test_merge_reports_conflicts_and_keeps_newer() {
+    let source = TeamRepository::with_store(InMemoryStore::new());
+    let target = TeamRepository::with_store(InMemoryStore::new());
+
+    let mut shared = member("John Doe", "john@example.com");
+    target.save_team_member(&shared).unwrap();
+
+    // The exporting side edits the shared member later and adds a new one
+    shared.role = "Tech Lead".to_string();
+    shared.updated_at += chrono::Duration::minutes(5);
+    source.save_team_member(&shared).unwrap();
+    source.save_team_member(&member("Jane Roe", "jane@example.com")).unwrap();
+
+    let exported = archive::export(source.store()).unwrap();
+
+    let preview = archive::import(target.store(), exported.clone(), ImportMode::Merge, true).unwrap();
+    assert_eq!(preview[0].conflicts.len(), 1);
+    assert_eq!(target.list_team_members().unwrap().len(), 1);
+
+    let reports = archive::import(target.store(), exported, ImportMode::Merge, false).unwrap();
+    let report = &reports[0];
+    assert_eq!(report.added, 1);
+    assert_eq!(report.updated, 1);
+    assert_eq!(report.conflicts[0].resolution, Resolution::TookIncoming);
+    assert_eq!(target.get_team_member(shared.id).unwrap().unwrap().role, "Tech Lead");
+}
+
+#[test]
+fn test_replace_overwrites_collection() {
+    let source = TeamRepository::with_store(InMemoryStore::new());
+    let target = TeamRepository::with_store(InMemoryStore::new());
+
+    source.save_team_member(&member("John Doe", "john@example.com")).unwrap();
+    target.save_team_member(&member("Jane Roe", "jane@example.com")).unwrap();
+
+    let exported = archive::export(source.store()).unwrap();
+    archive::import(target.store(), exported, ImportMode::Replace, false).unwrap();
+
+    let members = target.list_team_members().unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].name, "John Doe");
+}
+
+#[test]
+fn test_merge_rejects_duplicate_member_email() {
+    let source = TeamRepository::with_store(InMemoryStore::new());
+    let target = TeamRepository::with_store(InMemoryStore::new());
+    target.save_team_member(&member("John Doe", "john@example.com")).unwrap();
+    source.save_team_member(&member("Johnny", "JOHN@example.com")).unwrap();
+
+    let exported = archive::export(source.store()).unwrap();
+    let err = archive::import(target.store(), exported.clone(), ImportMode::Merge, true).unwrap_err();
+    assert!(err.to_string().contains("member Johnny"), "{}", err);
+
+    let err = archive::import(target.store(), exported, ImportMode::Merge, false).unwrap_err();
+    assert!(format!("{:#}", err).contains("already used by John Doe"), "{:#}", err);
+    assert!(matches!(
+        err.downcast_ref::<StorageError>(),
+        Some(StorageError::InvalidMember { source: MemberError::DuplicateEmail { .. }, .. })
+    ));
+    let members = target.list_team_members().unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].name, "John Doe");
+}
@@ -0,0 +1,107 @@
+use chrono::NaiveDate;
+use timeless::claude::context::ConversationContext;
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, TeamRepository};
+
+fn date(s: &str) -> NaiveDate {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_assignment_validation_and_activity() {
+    let member = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let project = Project::new("Apollo".to_string(), "Moonshot".to_string());
+
+    let assignment = Assignment::new(member.id, project.id, 50, date("2025-01-01")).with_end_date(date("2025-03-31"));
+    assert!(assignment.validate().is_ok());
+    assert!(assignment.is_active_on(date("2025-03-31")));
+    assert!(!assignment.is_active_on(date("2025-04-01")));
+
+    assert!(Assignment::new(member.id, project.id, 0, date("2025-01-01")).validate().is_err());
+    assert!(Assignment::new(member.id, project.id, 120, date("2025-01-01")).validate().is_err());
+    let backwards = Assignment::new(member.id, project.id, 50, date("2025-02-01")).with_end_date(date("2025-01-01"));
+    assert!(backwards.validate().is_err());
+}
+
+#[test]
+fn test_projects_for_member_and_members_on_project() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let john = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let jane = TeamMember::new("Jane Roe".to_string(), "jane@example.com".to_string(), "Designer".to_string());
+    let apollo = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    let gemini = Project::new("Gemini".to_string(), "Two-seater".to_string());
+    for member in [&john, &jane] {
+        repo.save_team_member(member).unwrap();
+    }
+    for project in [&apollo, &gemini] {
+        repo.save_project(project).unwrap();
+    }
+
+    repo.save_assignment(&Assignment::new(john.id, apollo.id, 30, date("2025-01-01"))).unwrap();
+    repo.save_assignment(&Assignment::new(john.id, gemini.id, 70, date("2025-01-01"))).unwrap();
+    repo.save_assignment(&Assignment::new(jane.id, apollo.id, 100, date("2025-01-01"))
+        .with_end_date(date("2025-01-31"))).unwrap();
+
+    let projects = repo.get_projects_for_member(john.id, date("2025-01-15")).unwrap();
+    let names: Vec<&str> = projects.iter().map(|(p, _)| p.name.as_str()).collect();
+    assert_eq!(names, vec!["Gemini", "Apollo"]);
+
+    assert_eq!(repo.get_members_on_project(apollo.id, date("2025-01-15")).unwrap().len(), 2);
+    assert_eq!(repo.get_members_on_project(apollo.id, date("2025-02-15")).unwrap().len(), 1);
+
+    // Invalid assignments never reach storage
+    assert!(repo.save_assignment(&Assignment::new(jane.id, gemini.id, 0, date("2025-01-01"))).is_err());
+
+    // Removing a member drops their assignments
+    repo.remove_team_member(john.id).unwrap();
+    assert_eq!(repo.list_assignments().unwrap().len(), 1);
+}
+
+#[test]
+fn test_end_assignments_keeps_history() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let john = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let apollo = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    repo.save_team_member(&john).unwrap();
+    repo.save_project(&apollo).unwrap();
+
+    let past = Assignment::new(john.id, apollo.id, 50, date("2024-01-01")).with_end_date(date("2024-06-30"));
+    let current = Assignment::new(john.id, apollo.id, 60, date("2025-01-01"));
+    let upcoming = Assignment::new(john.id, apollo.id, 20, date("2025-09-01"));
+    for assignment in [&past, &current, &upcoming] {
+        repo.save_assignment(assignment).unwrap();
+    }
+
+    assert_eq!(repo.end_assignments(john.id, apollo.id, date("2025-03-15")).unwrap(), 2);
+    let mut remaining = repo.get_assignments_for_member(john.id).unwrap();
+    remaining.sort_by_key(|a| a.start_date);
+    let ends: Vec<_> = remaining.iter().map(|a| (a.start_date, a.end_date)).collect();
+    assert_eq!(ends, vec![
+        (date("2024-01-01"), Some(date("2024-06-30"))),
+        (date("2025-01-01"), Some(date("2025-03-14"))),
+    ]);
+    assert!(repo.get_projects_for_member(john.id, date("2025-03-15")).unwrap().is_empty());
+    assert_eq!(repo.get_projects_for_member(john.id, date("2025-03-14")).unwrap().len(), 1);
+    assert_eq!(repo.end_assignments(john.id, apollo.id, date("2025-03-15")).unwrap(), 0);
+
+    // An assignment starting on the day it is ended never happened
+    repo.save_assignment(&Assignment::new(john.id, apollo.id, 30, date("2025-03-20"))).unwrap();
+    assert_eq!(repo.end_assignments(john.id, apollo.id, date("2025-03-20")).unwrap(), 1);
+    assert_eq!(repo.get_assignments_for_member(john.id).unwrap().len(), 2);
+}
+
+#[test]
+fn test_conversation_context_uses_main_project() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let john = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let apollo = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    repo.save_team_member(&john).unwrap();
+    repo.save_project(&apollo).unwrap();
+
+    let context = ConversationContext::for_member(&repo, john.id).unwrap();
+    assert_eq!(context.context_data.current_project, None);
+
+    repo.save_assignment(&Assignment::new(john.id, apollo.id, 80, date("2020-01-01"))).unwrap();
+    let context = ConversationContext::for_member(&repo, john.id).unwrap();
+    assert_eq!(context.context_data.current_project.as_deref(), Some("Apollo"));
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{member, report};
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, TeamRepository};
+use uuid::Uuid;
+
+#[test]
+fn test_from_report_reads_severity_tag_and_ticket() {
+    let blocker = Blocker::from_report("[high] Staging deploy stuck, see OPS-142", Uuid::new_v4(), Utc::now());
+    assert_eq!(blocker.severity, BlockerSeverity::High);
+    assert_eq!(blocker.description, "Staging deploy stuck, see OPS-142");
+    assert_eq!(blocker.ticket.as_deref(), Some("OPS-142"));
+    assert!(blocker.is_open());
+
+    let plain = Blocker::from_report("Waiting on design review", Uuid::new_v4(), Utc::now());
+    assert_eq!(plain.severity, BlockerSeverity::Medium);
+    assert!(plain.ticket.is_none());
+}
+
+#[test]
+fn test_severity_parsing_and_order() {
+    assert_eq!("crit".parse::<BlockerSeverity>().unwrap(), BlockerSeverity::Critical);
+    assert_eq!("Low".parse::<BlockerSeverity>().unwrap(), BlockerSeverity::Low);
+    assert!("urgent".parse::<BlockerSeverity>().is_err());
+    assert!(BlockerSeverity::Critical > BlockerSeverity::High);
+}
+
+#[test]
+fn test_repeated_blocker_is_carried_forward() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    report(&repo, &ann, "Waiting on API keys", Duration::days(3));
+    report(&repo, &ann, "waiting on  API keys", Duration::days(1));
+
+    let blockers = repo.list_blockers().unwrap();
+    assert_eq!(blockers.len(), 1);
+    assert!(blockers[0].age(Utc::now()) >= Duration::days(3));
+    assert!(blockers[0].last_reported_at > blockers[0].opened_at);
+}
+
+#[test]
+fn test_same_blocker_from_another_member_adds_a_reporter() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    let bob = member(&repo, "Bob");
+    report(&repo, &ann, "CI is down", Duration::days(1));
+    report(&repo, &bob, "[critical] CI is down", Duration::days(0));
+
+    let blockers = repo.list_blockers().unwrap();
+    assert_eq!(blockers.len(), 1);
+    assert_eq!(blockers[0].reporter_id, ann.id);
+    assert_eq!(blockers[0].also_reported_by, vec![bob.id]);
+    assert!(blockers[0].is_reported_by(bob.id));
+}
+
+#[test]
+fn test_resolved_blocker_is_reopened_as_new() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    report(&repo, &ann, "Flaky tests", Duration::days(2));
+    let mut blocker = repo.list_open_blockers().unwrap().remove(0);
+    blocker.resolve(Utc::now());
+    repo.save_blocker(&blocker).unwrap();
+    assert!(repo.list_open_blockers().unwrap().is_empty());
+
+    report(&repo, &ann, "Flaky tests", Duration::days(0));
+    assert_eq!(repo.list_blockers().unwrap().len(), 2);
+    assert_eq!(repo.list_open_blockers().unwrap().len(), 1);
+    assert!(repo.get_blocker(blocker.id).unwrap().unwrap().resolved_at.is_some());
+}
+
+#[test]
+fn test_new_blocker_defaults_to_members_project() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    let apollo = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    repo.save_project(&apollo).unwrap();
+    repo.save_assignment(&Assignment::new(ann.id, apollo.id, 80, Utc::now().date_naive() - Duration::days(7))).unwrap();
+
+    report(&repo, &ann, "Need database access", Duration::days(0));
+    assert_eq!(repo.list_open_blockers().unwrap()[0].project_id, Some(apollo.id));
+}
+
+#[test]
+fn test_resolving_keeps_reports_made_after_listing() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    let bob = member(&repo, "Bob");
+    report(&repo, &ann, "CI is down", Duration::days(1));
+    let listed = repo.list_open_blockers().unwrap().remove(0);
+
+    // Bob reports it between listing and resolving
+    report(&repo, &bob, "CI is down", Duration::days(0));
+    let resolved = repo.resolve_blocker(listed.id, Utc::now()).unwrap().unwrap();
+    assert!(!resolved.is_open());
+    assert_eq!(resolved.also_reported_by, vec![bob.id]);
+    assert_eq!(repo.get_blocker(listed.id).unwrap().unwrap().also_reported_by, vec![bob.id]);
+    assert!(repo.resolve_blocker(Uuid::new_v4(), Utc::now()).unwrap().is_none());
+
+    // Edits go through the stored copy too
+    let edited = repo.update_blocker(listed.id, |b| b.severity = BlockerSeverity::Critical).unwrap().unwrap();
+    assert_eq!(edited.severity, BlockerSeverity::Critical);
+    assert_eq!(edited.also_reported_by, vec![bob.id]);
+    assert!(!edited.is_open());
+}
@@ -0,0 +1,18 @@
+use chrono::{Duration, Utc};
+use timeless::models::{StatusUpdate, TeamMember};
+use timeless::storage::{InMemoryStore, TeamRepository};
+
+/// Saves a developer named `name` with a matching example.com address.
+pub fn member(repo: &TeamRepository<InMemoryStore>, name: &str) -> TeamMember {
+    let member = TeamMember::new(name.to_string(), format!("{}@example.com", name.to_lowercase()), "Developer".to_string());
+    repo.save_team_member(&member).unwrap();
+    member
+}
+
+/// Saves a status update from `member` reporting `blocker`, `age` ago.
+pub fn report(repo: &TeamRepository<InMemoryStore>, member: &TeamMember, blocker: &str, age: Duration) {
+    let mut update = StatusUpdate::new(member.id, "Working".to_string());
+    update.timestamp = Utc::now() - age;
+    update.add_blocker(blocker.to_string());
+    repo.save_status_update(&update).unwrap();
+}
@@ -0,0 +1,137 @@
+use timeless::config::{resolve_config, ConfigError, ConfigOverrides};
+use timeless::models::BlockerSeverity;
+
+const SAMPLE_CONFIG: &str = r#"
+[app]
+name = "Smart Team Manager"
+version = "0.1.0"
+data_dir = "./team-data"
+log_level = "warn"
+
+[team]
+name = "Platform Team"
+timezone = "UTC"
+working_hours = { start = "08:30", end = "16:30" }
+working_days = ["Monday", "Tuesday", "Wednesday", "Thursday"]
+
+[claude]
+enabled = false
+
+[storage]
+data_format = "json"
+"#;
+
+fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+#[test]
+fn test_load_from_toml() {
+    let config = resolve_config(Some(SAMPLE_CONFIG), env(&[]), &ConfigOverrides::default()).unwrap();
+
+    assert_eq!(config.app.data_dir, "./team-data");
+    assert_eq!(config.team.name, "Platform Team");
+    assert_eq!(config.team.working_hours.start, "08:30");
+    assert_eq!(config.team.working_days.len(), 4);
+    assert!(!config.claude.enabled);
+}
+
+#[test]
+fn test_partial_file_uses_defaults() {
+    let config = resolve_config(Some("[team]\nname = \"Core\"\n"), env(&[]), &ConfigOverrides::default()).unwrap();
+
+    assert_eq!(config.team.name, "Core");
+    assert_eq!(config.app.data_dir, "./data");
+    assert_eq!(config.team.working_hours.end, "17:00");
+}
+
+#[test]
+fn test_env_and_cli_overrides() {
+    let vars = env(&[
+        ("TIMELESS_APP__DATA_DIR", "/srv/timeless"),
+        ("TIMELESS_TEAM__WORKING_HOURS__START", "07:00"),
+        ("TIMELESS_TEAM__WORKING_DAYS", "Monday, Friday"),
+        ("TIMELESS_CLAUDE__ENABLED", "true"),
+        ("TIMELESS_CONFIG_PATH", "ignored.toml"),
+        ("PATH", "/usr/bin"),
+    ]);
+    let config = resolve_config(Some(SAMPLE_CONFIG), vars.clone(), &ConfigOverrides::default()).unwrap();
+
+    assert_eq!(config.app.data_dir, "/srv/timeless");
+    assert_eq!(config.team.working_hours.start, "07:00");
+    assert_eq!(config.team.working_days, vec!["Monday", "Friday"]);
+    assert!(config.claude.enabled);
+
+    // Command-line flags win over the environment
+    let overrides = ConfigOverrides {
+        data_dir: Some("./cli-data".to_string()),
+        log_level: Some("debug".to_string()),
+    };
+    let config = resolve_config(Some(SAMPLE_CONFIG), vars, &overrides).unwrap();
+    assert_eq!(config.app.data_dir, "./cli-data");
+    assert_eq!(config.app.log_level, "debug");
+}
+
+#[test]
+fn test_unknown_env_key_is_rejected() {
+    let result = resolve_config(None, env(&[("TIMELESS_APP__DATA_DRI", "/tmp")]), &ConfigOverrides::default());
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_field_level_validation_errors() {
+    let contents = SAMPLE_CONFIG
+        .replace("\"08:30\"", "\"8.30am\"")
+        .replace("\"Thursday\"", "\"Thurs-day\"");
+    let err = resolve_config(Some(&contents), env(&[]), &ConfigOverrides::default()).unwrap_err();
+
+    let ConfigError::Invalid(errors) = err.downcast_ref::<ConfigError>().unwrap();
+    let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, vec!["team.working_hours.start", "team.working_days[3]"]);
+
+    let memory = SAMPLE_CONFIG.replace("data_format = \"json\"", "data_format = \"memory\"");
+    let err = resolve_config(Some(&memory), env(&[]), &ConfigOverrides::default()).unwrap_err();
+    let ConfigError::Invalid(errors) = err.downcast_ref::<ConfigError>().unwrap();
+    assert_eq!(errors[0].field, "storage.data_format");
+}
+
+#[test]
+fn test_metrics_section_and_validation() {
+    let toml = "[metrics]\nvelocity_cap = 40.0\nmin_velocity = 0.0\n";
+    let config = resolve_config(Some(toml), env(&[("TIMELESS_METRICS__MAX_BLOCKER_RATIO", "0.25")]), &ConfigOverrides::default()).unwrap();
+    assert_eq!(config.metrics.velocity_cap, 40.0);
+    assert_eq!(config.metrics.min_velocity, 0.0);
+    assert_eq!(config.metrics.max_blocker_ratio, 0.25);
+    assert_eq!(config.metrics.satisfaction_weight, 0.4);
+
+    let bad = "[metrics]\nsatisfaction_weight = 0.0\nvelocity_weight = 0.0\nblocker_weight = 0.0\nvelocity_cap = 0.0\n";
+    let err = resolve_config(Some(bad), env(&[]), &ConfigOverrides::default()).unwrap_err();
+    let ConfigError::Invalid(errors) = err.downcast_ref::<ConfigError>().unwrap();
+    let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, ["metrics", "metrics.velocity_cap"]);
+}
+
+#[test]
+fn test_escalation_rules_and_validation() {
+    let toml = "[[escalation.rules]]\nname = \"stale-high\"\nmin_severity = \"high\"\nmin_open_hours = 24\nemail = [\"lead@example.com\"]\n\n\
+        [[escalation.rules]]\nname = \"widespread\"\nmin_reporters = 3\nslack_channel = \"#eng\"\n";
+    let config = resolve_config(Some(toml), env(&[]), &ConfigOverrides::default()).unwrap();
+    let rules = &config.escalation.rules;
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].min_severity, Some(BlockerSeverity::High));
+    assert_eq!(rules[0].min_open_hours, Some(24));
+    assert_eq!(rules[1].min_reporters, Some(3));
+    assert!(rules[1].email.is_empty());
+
+    let bad = "[[escalation.rules]]\nname = \"a\"\nmin_reporters = 0\nslack_channel = \"#eng\"\n\n[[escalation.rules]]\nname = \"a\"\n";
+    let err = resolve_config(Some(bad), env(&[]), &ConfigOverrides::default()).unwrap_err();
+    let ConfigError::Invalid(errors) = err.downcast_ref::<ConfigError>().unwrap();
+    let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, ["escalation.rules[0].min_reporters", "escalation.rules[1].name", "escalation.rules[1]", "escalation.rules[1]"]);
+    assert!(errors[2].message.contains("at least one of min_severity"));
+
+    // Owner and lead are targets on their own
+    let people = "[[escalation.rules]]\nname = \"stale\"\nmin_open_hours = 48\nnotify_owner = true\nnotify_lead = true\n";
+    let config = resolve_config(Some(people), env(&[]), &ConfigOverrides::default()).unwrap();
+    assert!(config.escalation.rules[0].notify_owner && config.escalation.rules[0].notify_lead);
+}
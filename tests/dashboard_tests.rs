@@ -0,0 +1,89 @@
+use chrono::{Duration, Utc};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use timeless::config::MetricsConfig;
+use timeless::dashboard::ui::{self, DashboardState};
+use timeless::dashboard::{format_age, DashboardData};
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, TeamRepository};
+
+fn seeded_repo() -> TeamRepository<InMemoryStore> {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = TeamMember::new("Ann Lee".to_string(), "ann@example.com".to_string(), "Developer".to_string());
+    let bob = TeamMember::new("Bob Ray".to_string(), "bob@example.com".to_string(), "Designer".to_string());
+    repo.save_team_member(&ann).unwrap();
+    repo.save_team_member(&bob).unwrap();
+
+    let mut old = StatusUpdate::new(ann.id, "Old work".to_string());
+    old.timestamp = Utc::now() - Duration::days(2);
+    old.add_blocker("Resolved since".to_string());
+    repo.save_status_update(&old).unwrap();
+    let mut resolved = repo.list_open_blockers().unwrap().remove(0);
+    resolved.resolve(Utc::now());
+    repo.save_blocker(&resolved).unwrap();
+    let mut latest = StatusUpdate::new(ann.id, "Shipping auth".to_string()).with_mood("great".to_string());
+    latest.add_blocker("Waiting on keys".to_string());
+    repo.save_status_update(&latest).unwrap();
+
+    let apollo = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    let mut paused = Project::new("Zeus".to_string(), "Paused".to_string());
+    paused.set_status(ProjectStatus::OnHold);
+    repo.save_project(&apollo).unwrap();
+    repo.save_project(&paused).unwrap();
+    repo.save_assignment(&Assignment::new(ann.id, apollo.id, 60, Utc::now().date_naive())).unwrap();
+
+    let mut metrics = TeamMetrics::new(Utc::now());
+    metrics.active_members = 2;
+    metrics.average_satisfaction = Some(8.0);
+    metrics.velocity = Some(40.0);
+    repo.save_team_metrics(&metrics).unwrap();
+
+    repo.save_ai_decision(&AIDecision::new("staffing".to_string(), "Apollo".to_string(), "Add a reviewer".to_string(), 0.9)).unwrap();
+    repo
+}
+
+#[test]
+fn test_dashboard_data_uses_latest_updates() {
+    let data = DashboardData::load(&seeded_repo(), "Core", &MetricsConfig::default(), Utc::now()).unwrap();
+
+    assert_eq!(data.roster.len(), 2);
+    assert_eq!(data.roster[0].latest.as_ref().unwrap().content, "Shipping auth");
+    assert!(data.roster[1].latest.is_none());
+    assert_eq!(data.open_blockers.len(), 1);
+    assert_eq!(data.open_blockers[0].text, "Waiting on keys");
+    assert_eq!(data.projects.len(), 1);
+    assert_eq!(data.projects[0].members, vec!["Ann Lee 60%"]);
+    assert!(data.health_score.is_some());
+    assert_eq!(data.decisions.len(), 1);
+}
+
+#[test]
+fn test_dashboard_renders_every_panel() {
+    let data = DashboardData::load(&seeded_repo(), "Core", &MetricsConfig::default(), Utc::now()).unwrap();
+    let mut state = DashboardState { status: "watching ./data".to_string(), ..Default::default() };
+    let mut terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+    terminal.draw(|frame| ui::draw(frame, &data, &mut state)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let screen: String = (0..buffer.area.height)
+        .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n");
+    for expected in ["Core", "Ann Lee", "Shipping auth", "no updates yet", "Waiting on keys", "Apollo", "Add a reviewer", "watching ./data"] {
+        assert!(screen.contains(expected), "missing {:?}", expected);
+    }
+    assert!(!screen.contains("Zeus"));
+
+    state.select_next(data.roster.len());
+    state.select_next(data.roster.len());
+    state.select_next(data.roster.len());
+    assert_eq!(state.roster.selected(), Some(1));
+}
+
+#[test]
+fn test_format_age() {
+    assert_eq!(format_age(Duration::seconds(20)), "now");
+    assert_eq!(format_age(Duration::minutes(5)), "5m");
+    assert_eq!(format_age(Duration::hours(3)), "3h");
+    assert_eq!(format_age(Duration::days(2)), "2d");
+}
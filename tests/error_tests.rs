@@ -0,0 +1,37 @@
+use std::time::Duration;
+use timeless::cli::error::*;
+use timeless::models::{MemberError, ProjectError, ProjectStatus};
+use timeless::storage::resolver::ResolveError;
+use timeless::storage::{RepositoryError, StorageError};
+use uuid::Uuid;
+
+fn code(err: impl Into<anyhow::Error>) -> i32 {
+    exit_code(&err.into())
+}
+
+#[test]
+fn test_exit_codes_per_variant() {
+    let lock = StorageError::LockTimeout { path: "projects.lock".into(), waited: Duration::from_secs(5) };
+    assert_eq!(code(lock), EXIT_LOCKED);
+
+    let schema = StorageError::UnsupportedSchema { collection: "projects".to_string(), found: 9, supported: 2 };
+    assert_eq!(code(RepositoryError::from(schema)), EXIT_SCHEMA);
+
+    let corrupt = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+    assert_eq!(code(StorageError::Corrupt { path: "projects.json".into(), source: corrupt }), EXIT_CORRUPT);
+
+    let duplicate = MemberError::DuplicateEmail { email: "a@b.co".to_string(), id: Uuid::new_v4(), name: "A".to_string() };
+    assert_eq!(code(RepositoryError::from(duplicate.clone())), EXIT_CONFLICT);
+    assert_eq!(code(StorageError::InvalidMember { id: "1".to_string(), name: "A".to_string(), source: duplicate }), EXIT_CONFLICT);
+    assert_eq!(code(RepositoryError::from(MemberError::EmptyName)), EXIT_INVALID);
+    assert_eq!(code(ResolveError::NotFound("zed".to_string())), EXIT_NOT_FOUND);
+    let reopen = ProjectError::TransitionNotAllowed { name: "Apollo".to_string(), from: ProjectStatus::Completed, to: ProjectStatus::Active };
+    assert_eq!(code(reopen), EXIT_INVALID);
+    assert_eq!(code(anyhow::anyhow!("something else")), EXIT_FAILURE);
+}
+
+#[test]
+fn test_exit_code_looks_through_context() {
+    let err = anyhow::Error::from(RepositoryError::from(MemberError::EmptyName)).context("could not add user");
+    assert_eq!(exit_code(&err), EXIT_INVALID);
+}
@@ -0,0 +1,130 @@
+mod common;
+
+use chrono::{Duration, Utc};
+use common::{member, report};
+use std::collections::HashMap;
+use timeless::config::EscalationRule;
+use timeless::escalation::{self, EscalationTarget};
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, TeamRepository};
+
+fn rules() -> Vec<EscalationRule> {
+    vec![
+        EscalationRule {
+            name: "stale-high".to_string(),
+            min_severity: Some(BlockerSeverity::High),
+            min_open_hours: Some(24),
+            email: vec!["lead@example.com".to_string()],
+            ..Default::default()
+        },
+        EscalationRule {
+            name: "widespread".to_string(),
+            min_reporters: Some(3),
+            slack_channel: Some("#eng".to_string()),
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+fn test_rules_match_severity_age_and_reporters() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let (ann, bob, cat) = (member(&repo, "Ann"), member(&repo, "Bob"), member(&repo, "Cat"));
+    report(&repo, &ann, "[high] Prod database is read-only", Duration::hours(30));
+    report(&repo, &ann, "[critical] Signing key expired", Duration::hours(2));
+    report(&repo, &ann, "CI is down", Duration::hours(5));
+    report(&repo, &bob, "CI is down", Duration::hours(4));
+    report(&repo, &cat, "ci is DOWN", Duration::hours(3));
+
+    let pending = escalation::pending(&repo, &rules(), Utc::now()).unwrap();
+    let sent: Vec<(&str, &str, String)> = pending.iter()
+        .map(|p| (p.blocker.description.as_str(), p.rule.name.as_str(), p.target.to_string()))
+        .collect();
+    assert_eq!(sent, vec![
+        ("Prod database is read-only", "stale-high", "email:lead@example.com".to_string()),
+        ("CI is down", "widespread", "slack:#eng".to_string()),
+    ]);
+}
+
+#[test]
+fn test_recorded_escalations_are_not_sent_twice() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    report(&repo, &ann, "[high] Prod database is read-only", Duration::hours(30));
+    let mut rules = rules();
+    rules[0].slack_channel = Some("#eng".to_string());
+
+    let pending = escalation::pending(&repo, &rules, Utc::now()).unwrap();
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending[0].target, EscalationTarget::Slack("#eng".to_string()));
+    let first = &pending[0];
+    repo.save_escalation(&Escalation::new(first.blocker.id, first.rule.name.clone(), first.target.to_string(), Utc::now())).unwrap();
+
+    let pending = escalation::pending(&repo, &rules, Utc::now()).unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].target, EscalationTarget::Email("lead@example.com".to_string()));
+
+    let mut blocker = pending[0].blocker.clone();
+    blocker.resolve(Utc::now());
+    repo.save_blocker(&blocker).unwrap();
+    assert!(escalation::pending(&repo, &rules, Utc::now()).unwrap().is_empty());
+}
+
+#[test]
+fn test_message_names_people_and_ticket() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    report(&repo, &ann, "[high] Release blocked on OPS-7", Duration::hours(48));
+    let pending = escalation::pending(&repo, &rules(), Utc::now()).unwrap();
+
+    let names = HashMap::from([(ann.id, ann.name.clone())]);
+    let message = escalation::message(&pending[0], &names, Utc::now());
+    assert!(message.contains("rule \"stale-high\": Release blocked on OPS-7"));
+    assert!(message.contains("Severity high, open 2d, reported by Ann"));
+    assert!(message.contains("Owner: nobody"));
+    assert!(message.contains("Ticket: OPS-7"));
+}
+
+#[test]
+fn test_owner_and_lead_targets() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = member(&repo, "Ann");
+    let mut lead = TeamMember::new("Lou".to_string(), "lou@example.com".to_string(), "Tech Lead".to_string());
+    repo.save_team_member(&lead).unwrap();
+    report(&repo, &ann, "[high] Prod database is read-only", Duration::hours(30));
+    let mut blocker = repo.list_open_blockers().unwrap().remove(0);
+    blocker.owner_id = Some(ann.id);
+    repo.save_blocker(&blocker).unwrap();
+
+    let rule = EscalationRule {
+        name: "people".to_string(),
+        min_open_hours: Some(24),
+        email: vec!["lou@example.com".to_string()],
+        notify_owner: true,
+        notify_lead: true,
+        ..Default::default()
+    };
+    let targets: Vec<String> = escalation::pending(&repo, std::slice::from_ref(&rule), Utc::now()).unwrap()
+        .iter()
+        .map(|p| p.target.to_string())
+        .collect();
+    // Lou is both listed and a lead, but is only sent one
+    assert_eq!(targets, ["email:lou@example.com", "email:ann@example.com"]);
+
+    // A lead on the blocker's project is preferred over the rest of the team
+    let project = Project::new("Apollo".to_string(), String::new());
+    repo.save_project(&project).unwrap();
+    let mut project_lead = TeamMember::new("Pia".to_string(), "pia@example.com".to_string(), "Lead Engineer".to_string());
+    repo.save_team_member(&project_lead).unwrap();
+    repo.save_assignment(&Assignment::new(project_lead.id, project.id, 50, Utc::now().date_naive())).unwrap();
+    blocker.project_id = Some(project.id);
+    let rule = EscalationRule { email: Vec::new(), notify_owner: false, ..rule };
+    let targets = escalation::targets_for(&repo, &rule, &blocker, Utc::now()).unwrap();
+    assert_eq!(targets, vec![EscalationTarget::Email("pia@example.com".to_string())]);
+
+    lead.role = "Developer".to_string();
+    project_lead.role = "Developer".to_string();
+    repo.save_team_member(&lead).unwrap();
+    repo.save_team_member(&project_lead).unwrap();
+    assert!(escalation::targets_for(&repo, &rule, &blocker, Utc::now()).unwrap().is_empty());
+}
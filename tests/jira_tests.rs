@@ -0,0 +1,89 @@
+use anyhow::Result;
+use chrono::{TimeZone, Utc};
+use std::cell::RefCell;
+use timeless::claude::structured::{self, extract_json, parse, Structured, StructuredResponse};
+use timeless::services::jira::is_ticket_key;
+use timeless::services::{JiraProjectMetrics, JiraTicket, JiraTickets};
+
+const TICKETS: &str = r#"{"tickets": [
+    {"key": "TIM-12", "summary": "Login page", "status": "In Progress", "assignee": "Ann Lee", "priority": "High", "updated": "2025-08-01T10:15:30.000+0000"},
+    {"key": "TIM-13", "summary": "Logout", "status": "To Do", "assignee": null}
+]}"#;
+
+/// Runs `structured::request` against canned replies, returning the result
+/// and the prompts that were sent.
+async fn request<T: StructuredResponse>(replies: &[&str]) -> (Result<Structured<T>>, Vec<String>) {
+    let prompts = RefCell::new(Vec::new());
+    let replies = RefCell::new(replies.iter().map(|r| r.to_string()).collect::<Vec<_>>());
+    let result = structured::request::<T, _, _>("List tickets", |prompt| {
+        prompts.borrow_mut().push(prompt);
+        let reply = replies.borrow_mut().remove(0);
+        async move { Ok(reply) }
+    }).await;
+    (result, prompts.into_inner())
+}
+
+#[test]
+fn test_parse_tickets() {
+    let tickets: JiraTickets = parse(TICKETS).unwrap();
+    assert_eq!(tickets.tickets.len(), 2);
+    let first = &tickets.tickets[0];
+    assert_eq!(first.key, "TIM-12");
+    assert_eq!(first.assignee.as_deref(), Some("Ann Lee"));
+    assert_eq!(first.updated, Some(Utc.with_ymd_and_hms(2025, 8, 1, 10, 15, 30).unwrap()));
+    assert!(tickets.tickets[1].assignee.is_none());
+    assert!(tickets.tickets[1].updated.is_none());
+
+    let dated: JiraTicket = parse(r#"{"key": "A1-7", "summary": "x", "status": "Done", "updated": "2025-08-02"}"#).unwrap();
+    assert_eq!(dated.updated, Some(Utc.with_ymd_and_hms(2025, 8, 2, 0, 0, 0).unwrap()));
+}
+
+#[test]
+fn test_extract_json_from_chatty_replies() {
+    let fenced = format!("Here are the tickets:\n```json\n{}\n```\nLet me know!", TICKETS);
+    assert!(parse::<JiraTickets>(&fenced).is_ok());
+    assert_eq!(extract_json("Sure! {\"a\": 1} Done."), "{\"a\": 1}");
+    assert_eq!(extract_json("no json here"), "no json here");
+}
+
+#[test]
+fn test_validation_errors_name_the_field() {
+    let bad_key = r#"{"tickets": [{"key": "login", "summary": "x", "status": "Done"}]}"#;
+    assert_eq!(parse::<JiraTickets>(bad_key).unwrap_err(), r#"tickets[0]: key: expected an issue key like PROJ-123, got "login""#);
+
+    let missing = r#"{"tickets": [{"key": "TIM-1", "summary": "x"}]}"#;
+    assert!(parse::<JiraTickets>(missing).unwrap_err().contains("missing field `status`"));
+
+    let metrics = r#"{"project": "TIM", "created": 4, "completed": 2, "in_progress": 1, "blockers": [{"key": "TIM-9", "summary": "x", "status": ""}]}"#;
+    assert_eq!(parse::<JiraProjectMetrics>(metrics).unwrap_err(), "blockers[0]: TIM-9: status must not be empty");
+
+    assert!(is_ticket_key("PROJ2-123"));
+    assert!(!is_ticket_key("proj-1"));
+    assert!(!is_ticket_key("PROJ-"));
+    assert!(is_ticket_key("MY_APP-7"));
+    assert!(!is_ticket_key("A-1"));
+}
+
+#[tokio::test]
+async fn test_request_parses_first_reply() {
+    let (result, prompts) = request::<JiraTickets>(&[TICKETS]).await;
+    assert_eq!(result.unwrap().parsed().unwrap().tickets.len(), 2);
+    assert_eq!(prompts.len(), 1);
+    assert!(prompts[0].starts_with("List tickets"));
+    assert!(prompts[0].contains("\"required\": [\"tickets\"]"));
+}
+
+#[tokio::test]
+async fn test_request_retries_once_with_the_error() {
+    let (result, prompts) = request::<JiraTickets>(&["I found 2 tickets: TIM-12 and TIM-13", TICKETS]).await;
+    assert!(matches!(result.unwrap(), Structured::Parsed(t) if t.tickets.len() == 2));
+    assert_eq!(prompts.len(), 2);
+    assert!(prompts[1].contains("Your previous answer could not be used: invalid JSON"));
+}
+
+#[tokio::test]
+async fn test_request_falls_back_to_raw_text() {
+    let (result, prompts) = request::<JiraTickets>(&["nothing useful", "TIM-12 is in progress"]).await;
+    assert_eq!(result.unwrap(), Structured::Raw("TIM-12 is in progress".to_string()));
+    assert_eq!(prompts.len(), 2);
+}
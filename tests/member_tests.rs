@@ -0,0 +1,53 @@
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, RepositoryError, TeamRepository};
+
+fn member(name: &str, email: &str) -> TeamMember {
+    TeamMember::new(name.to_string(), email.to_string(), "Developer".to_string())
+}
+
+#[test]
+fn test_member_validation() {
+    assert!(member("John Doe", "john@example.com").validate().is_ok());
+    assert_eq!(member("  ", "john@example.com").validate(), Err(MemberError::EmptyName));
+
+    for email in ["john", "john@", "@example.com", "john@example", "john doe@example.com", "a@b@c.com", "john@example..com"] {
+        assert!(
+            matches!(member("John", email).validate(), Err(MemberError::InvalidEmail(_))),
+            "{} should be rejected", email
+        );
+    }
+
+    for slack_id in ["U012AB3CD", "W0123456", "@john.doe", "@alice"] {
+        assert!(member("John", "john@example.com").with_slack_id(slack_id.to_string()).validate().is_ok());
+    }
+    for slack_id in ["john", "u012ab3cd", "U12", "@", "@john doe"] {
+        assert!(
+            matches!(
+                member("John", "john@example.com").with_slack_id(slack_id.to_string()).validate(),
+                Err(MemberError::InvalidSlackId(_))
+            ),
+            "{} should be rejected", slack_id
+        );
+    }
+}
+
+#[test]
+fn test_repository_enforces_uniqueness() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let john = member("John Doe", "john@example.com").with_slack_id("U012AB3CD".to_string());
+    repo.save_team_member(&john).unwrap();
+
+    // Saving the same member again is an update, not a duplicate
+    repo.save_team_member(&john).unwrap();
+
+    let err = repo.save_team_member(&member("Johnny", "JOHN@example.com")).unwrap_err();
+    assert!(matches!(err, RepositoryError::Member(MemberError::DuplicateEmail { id, .. }) if id == john.id));
+
+    let twin = member("Jane Roe", "jane@example.com").with_slack_id("U012AB3CD".to_string());
+    let err = repo.save_team_member(&twin).unwrap_err();
+    assert!(matches!(err, RepositoryError::Member(MemberError::DuplicateSlackId { .. })));
+
+    let err = repo.save_team_member(&member("", "nobody@example.com")).unwrap_err();
+    assert!(matches!(err, RepositoryError::Member(MemberError::EmptyName)));
+    assert_eq!(repo.list_team_members().unwrap().len(), 1);
+}
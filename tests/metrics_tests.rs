@@ -0,0 +1,133 @@
+use chrono::{Duration, TimeZone, Utc};
+use timeless::cli::metrics::{parse_age, parse_since};
+use timeless::config::MetricsConfig;
+use timeless::metrics::{aggregate, compute, mood_score};
+use timeless::models::*;
+use timeless::storage::{InMemoryStore, TeamRepository};
+use uuid::Uuid;
+
+fn update(member_id: Uuid, days_ago: i64, mood: Option<&str>, blockers: &[&str]) -> StatusUpdate {
+    let mut update = StatusUpdate::new(member_id, "Working".to_string());
+    update.timestamp = Utc::now() - Duration::days(days_ago);
+    update.mood = mood.map(str::to_string);
+    for blocker in blockers {
+        update.add_blocker(blocker.to_string());
+    }
+    update
+}
+
+#[test]
+fn test_mood_score_mapping() {
+    assert_eq!(mood_score("great"), Some(9.0));
+    assert_eq!(mood_score(" Stressed "), Some(3.0));
+    assert_eq!(mood_score("7"), Some(7.0));
+    assert_eq!(mood_score("8/10"), Some(8.0));
+    assert_eq!(mood_score("15"), Some(10.0));
+    assert_eq!(mood_score("purple"), None);
+}
+
+#[test]
+fn test_aggregate_daily_and_weekly() {
+    let (ann, bob) = (Uuid::new_v4(), Uuid::new_v4());
+    let monday = Utc.with_ymd_and_hms(2025, 3, 10, 9, 0, 0).unwrap();
+    let at = |update: StatusUpdate, offset: Duration| StatusUpdate { timestamp: monday + offset, ..update };
+
+    let updates = vec![
+        at(update(ann, 0, Some("great"), &["CI is red"]), Duration::zero()),
+        at(update(ann, 0, Some("ok"), &["CI is red"]), Duration::hours(3)),
+        at(update(bob, 0, Some("stressed"), &["No access", "[high]  ci is  RED"]), Duration::hours(1)),
+        at(update(bob, 0, None, &[]), Duration::days(2)),
+    ];
+
+    let daily = aggregate(&updates, MetricsPeriod::Daily);
+    assert_eq!(daily.len(), 2);
+    assert_eq!(daily[0].date, Utc.with_ymd_and_hms(2025, 3, 10, 0, 0, 0).unwrap());
+    assert_eq!(daily[0].period, Some(MetricsPeriod::Daily));
+    assert_eq!(daily[0].active_members, 2);
+    // "CI is red" counts once, however often and by whomever it was reported
+    assert_eq!(daily[0].blockers_count, 2);
+    // Ann averages 7.5, Bob 3
+    assert!((daily[0].average_satisfaction.unwrap() - 5.25).abs() < 1e-5);
+    assert_eq!(daily[0].velocity, None);
+    assert_eq!(daily[1].active_members, 1);
+    assert_eq!(daily[1].average_satisfaction, None);
+    // Unknown velocity and satisfaction are not concerns
+    assert!(daily[1].concerns(&MetricsConfig::default()).is_empty());
+    assert!((daily[1].calculate_health_score() - 10.0).abs() < 1e-4);
+
+    let weekly = aggregate(&updates, MetricsPeriod::Weekly);
+    assert_eq!(weekly.len(), 1);
+    assert_eq!(weekly[0].active_members, 2);
+    assert_eq!(weekly[0].date, daily[0].date);
+}
+
+#[test]
+fn test_compute_replaces_earlier_snapshots() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let ann = Uuid::new_v4();
+    repo.save_status_update(&update(ann, 1, Some("good"), &[])).unwrap();
+    repo.save_status_update(&update(ann, 3, Some("bad"), &["Flaky tests"])).unwrap();
+
+    let since = Utc::now() - Duration::days(7);
+    assert_eq!(compute(&repo, since, MetricsPeriod::Daily, true).unwrap().len(), 2);
+    assert!(repo.get_latest_team_metrics().unwrap().is_none());
+
+    let first = compute(&repo, since, MetricsPeriod::Daily, false).unwrap();
+    let mut stored = repo.get_latest_team_metrics().unwrap().unwrap();
+    stored.velocity = Some(42.0);
+    repo.save_team_metrics(&stored).unwrap();
+
+    let second = compute(&repo, since, MetricsPeriod::Daily, false).unwrap();
+    assert_eq!(first.iter().map(|m| m.id).collect::<Vec<_>>(), second.iter().map(|m| m.id).collect::<Vec<_>>());
+    assert_eq!(repo.get_team_metrics_range(since - Duration::days(1), Utc::now()).unwrap().len(), 2);
+    assert_eq!(repo.get_latest_team_metrics().unwrap().unwrap().velocity, Some(42.0));
+
+    // Weekly snapshots are kept apart from daily ones
+    compute(&repo, since, MetricsPeriod::Weekly, false).unwrap();
+    let all = repo.get_team_metrics_range(since - Duration::days(7), Utc::now()).unwrap();
+    assert!(all.iter().any(|m| m.period == Some(MetricsPeriod::Weekly)));
+    assert_eq!(all.iter().filter(|m| m.period == Some(MetricsPeriod::Daily)).count(), 2);
+}
+
+#[test]
+fn test_parse_since_and_age() {
+    let now = Utc.with_ymd_and_hms(2025, 3, 14, 12, 0, 0).unwrap();
+    assert_eq!(parse_since("2025-03-01", now).unwrap(), Utc.with_ymd_and_hms(2025, 3, 1, 0, 0, 0).unwrap());
+    assert_eq!(parse_since("3d", now).unwrap(), now - Duration::days(3));
+    assert_eq!(parse_age("2w").unwrap(), Duration::days(14));
+    assert_eq!(parse_age("12h").unwrap(), Duration::hours(12));
+    assert!(parse_since("yesterday", now).is_err());
+    assert!(parse_age("d").is_err());
+    assert!(parse_age("-3d").is_err());
+    assert!(parse_age("0h").is_err());
+    assert!(parse_since("-2w", now).is_err());
+    assert!(parse_age("999999999999999d").is_err());
+    assert!(parse_since("99999999999d", now).is_err());
+}
+
+#[test]
+fn test_configured_health_score_and_concerns() {
+    let mut metrics = TeamMetrics::new(Utc::now());
+    metrics.active_members = 4;
+    metrics.blockers_count = 3;
+    metrics.average_satisfaction = Some(8.0);
+    metrics.velocity = Some(30.0);
+
+    // Defaults keep the original 0.4/0.3/0.3 weighting
+    let defaults = MetricsConfig::default();
+    assert!((metrics.calculate_health_score() - 4.85).abs() < 1e-4);
+    assert_eq!(metrics.calculate_health_score(), metrics.health_score_with(&defaults));
+    assert_eq!(metrics.concerns(&defaults), vec![Concern::HighBlockerRatio { value: 0.75, threshold: 0.5 }]);
+    assert!(metrics.has_concerning_metrics());
+
+    // A team with a smaller velocity scale
+    let small_scale = MetricsConfig { velocity_cap: 30.0, max_blocker_ratio: 1.0, ..MetricsConfig::default() };
+    assert!(metrics.health_score_with(&small_scale) > metrics.calculate_health_score());
+    assert!(metrics.concerns(&small_scale).is_empty());
+
+    metrics.velocity = Some(0.0);
+    metrics.average_satisfaction = Some(2.0);
+    let concerns = metrics.concerns(&MetricsConfig { min_velocity: 0.0, ..small_scale });
+    assert_eq!(concerns.len(), 1);
+    assert_eq!(concerns[0].to_string(), "satisfaction 2.0/10 is below 5.0");
+}
@@ -0,0 +1,105 @@
+use serde_json::json;
+use tempfile::TempDir;
+use timeless::storage::migrations::{self, Change, CURRENT_SCHEMA_VERSION};
+use timeless::storage::{InMemoryStore, JsonStore, Storage, TeamRepository};
+
+const LEGACY_MEMBERS: &str = r#"{
+  "items": {
+    "5f0c1d4e-2b8a-4c57-9a43-1f1e5b6a7c80": {
+      "id": "5f0c1d4e-2b8a-4c57-9a43-1f1e5b6a7c80",
+      "name": "John Doe",
+      "email": "john@example.com",
+      "slack_id": null,
+      "role": "Developer",
+      "created_at": "2025-07-07T19:18:16.915222Z",
+      "updated_at": "2025-07-07T19:18:16.915222Z"
+    }
+  },
+  "last_updated": "2025-07-07T19:18:16.915222Z"
+}"#;
+
+fn legacy_store() -> (TempDir, JsonStore) {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(temp_dir.path().join("team_members.json"), LEGACY_MEMBERS).unwrap();
+    let store = JsonStore::new(temp_dir.path()).unwrap();
+    (temp_dir, store)
+}
+
+#[test]
+fn test_legacy_files_load_without_migrating_on_disk() {
+    let (temp_dir, _) = legacy_store();
+    let repo = TeamRepository::new(temp_dir.path()).unwrap();
+
+    assert_eq!(repo.list_team_members().unwrap().len(), 1);
+
+    let raw = repo.store().load_raw("team_members").unwrap().unwrap();
+    assert_eq!(migrations::schema_version(&raw), 0);
+}
+
+#[test]
+fn test_migrate_dry_run_then_apply() {
+    let (_temp_dir, store) = legacy_store();
+
+    let reports = migrations::migrate_store(&store, true).unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].from_version, 0);
+    assert_eq!(reports[0].to_version, CURRENT_SCHEMA_VERSION);
+    assert!(reports[0].changes.contains(&Change::Added {
+        path: "schema_version".to_string(),
+        value: json!(CURRENT_SCHEMA_VERSION),
+    }));
+    let raw = store.load_raw("team_members").unwrap().unwrap();
+    assert_eq!(migrations::schema_version(&raw), 0);
+
+    migrations::migrate_store(&store, false).unwrap();
+    let raw = store.load_raw("team_members").unwrap().unwrap();
+    assert_eq!(migrations::schema_version(&raw), CURRENT_SCHEMA_VERSION);
+
+    let reports = migrations::migrate_store(&store, false).unwrap();
+    assert!(reports.iter().all(|r| r.is_up_to_date()));
+}
+
+#[test]
+fn test_newer_schema_is_rejected() {
+    let temp_dir = TempDir::new().unwrap();
+    let mut future: serde_json::Value = serde_json::from_str(LEGACY_MEMBERS).unwrap();
+    future["schema_version"] = json!(CURRENT_SCHEMA_VERSION + 1);
+    std::fs::write(temp_dir.path().join("team_members.json"), future.to_string()).unwrap();
+
+    let repo = TeamRepository::new(temp_dir.path()).unwrap();
+    let err = repo.list_team_members().unwrap_err();
+    assert!(err.to_string().contains("upgrade timeless"));
+}
+
+#[test]
+fn test_diff_reports_field_changes() {
+    let before = json!({ "items": { "a": { "name": "Old", "role": "Dev" } }, "last_updated": "x" });
+    let after = json!({ "items": { "a": { "name": "New", "team": "Core" } }, "last_updated": "y" });
+
+    let changes: Vec<String> = migrations::diff(&before, &after).iter().map(|c| c.to_string()).collect();
+    assert_eq!(changes, vec![
+        "~ items.a.name: \"Old\" -> \"New\"",
+        "- items.a.role: \"Dev\"",
+        "+ items.a.team: \"Core\"",
+    ]);
+}
+
+#[test]
+fn test_in_memory_store_upgrades_legacy_data() {
+    let store = InMemoryStore::new();
+    let legacy = json!({"items": {"6a1f9a53-0c5e-4f6e-8d1b-2b0f3c4d5e6f": {
+        "id": "6a1f9a53-0c5e-4f6e-8d1b-2b0f3c4d5e6f",
+        "name": "Apollo",
+        "description": "Checkout rewrite",
+        "status": "Active",
+        "created_at": "2025-07-07T19:18:16.915222Z",
+        "updated_at": "2025-07-07T19:18:16.915222Z"
+    }}, "last_updated": "2025-07-07T19:18:16.915222Z"});
+    store.save("projects", &legacy).unwrap();
+
+    let repo = TeamRepository::with_store(store);
+    let projects = repo.list_projects().unwrap();
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].status_history.is_empty());
+    assert_eq!(migrations::schema_version(&repo.store().load_raw("projects").unwrap().unwrap()), 0);
+}
@@ -0,0 +1,81 @@
+use tempfile::TempDir;
+use timeless::models::*;
+use timeless::storage::{SqliteStore, Storage, TeamRepository};
+
+#[test]
+fn test_status_transitions_are_validated() {
+    let mut project = Project::new("Apollo".to_string(), "Moonshot".to_string());
+
+    project.transition_to(ProjectStatus::OnHold, false, Some("Waiting on vendor".to_string())).unwrap();
+    project.transition_to(ProjectStatus::Cancelled, false, None).unwrap();
+
+    // Cancelled projects stay closed unless forced
+    let err = project.transition_to(ProjectStatus::Active, false, None).unwrap_err();
+    assert_eq!(err, ProjectError::TransitionNotAllowed {
+        name: "Apollo".to_string(),
+        from: ProjectStatus::Cancelled,
+        to: ProjectStatus::Active,
+    });
+    assert!(!err.to_string().contains("--force"));
+    assert!(matches!(
+        project.transition_to(ProjectStatus::Cancelled, true, None),
+        Err(ProjectError::AlreadyInStatus { .. })
+    ));
+    project.transition_to(ProjectStatus::Active, true, None).unwrap();
+
+    let history: Vec<(ProjectStatus, ProjectStatus, bool)> =
+        project.status_history.iter().map(|c| (c.from, c.to, c.forced)).collect();
+    assert_eq!(history, vec![
+        (ProjectStatus::Active, ProjectStatus::OnHold, false),
+        (ProjectStatus::OnHold, ProjectStatus::Cancelled, false),
+        (ProjectStatus::Cancelled, ProjectStatus::Active, true),
+    ]);
+    assert_eq!(project.status_history[0].note.as_deref(), Some("Waiting on vendor"));
+}
+
+#[test]
+fn test_status_parsing() {
+    assert_eq!("on-hold".parse::<ProjectStatus>().unwrap(), ProjectStatus::OnHold);
+    assert_eq!("OnHold".parse::<ProjectStatus>().unwrap(), ProjectStatus::OnHold);
+    assert_eq!("canceled".parse::<ProjectStatus>().unwrap(), ProjectStatus::Cancelled);
+    assert!("paused".parse::<ProjectStatus>().is_err());
+}
+
+const LEGACY_PROJECT: &str = r#"{
+  "id": "0b7f3a52-9d7e-4c1a-8f11-3c2d4e5f6a7b",
+  "name": "Apollo",
+  "description": "Moonshot",
+  "status": "Active",
+  "created_at": "2025-07-07T19:18:16.915222Z",
+  "updated_at": "2025-07-07T19:18:16.915222Z"
+}"#;
+
+#[test]
+fn test_legacy_projects_gain_history_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let collection = format!(r#"{{ "items": {{ "0b7f3a52-9d7e-4c1a-8f11-3c2d4e5f6a7b": {} }}, "last_updated": "2025-07-07T19:18:16.915222Z" }}"#, LEGACY_PROJECT);
+    std::fs::write(temp_dir.path().join("projects.json"), collection).unwrap();
+
+    let repo = TeamRepository::new(temp_dir.path()).unwrap();
+    let projects = repo.list_projects().unwrap();
+    assert_eq!(projects.len(), 1);
+    assert!(projects[0].status_history.is_empty());
+    assert!(!projects[0].is_archived());
+}
+
+#[test]
+fn test_legacy_projects_gain_history_sqlite() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = SqliteStore::new(temp_dir.path()).unwrap();
+    let legacy: serde_json::Value = serde_json::from_str(LEGACY_PROJECT).unwrap();
+    store.save("projects", &serde_json::json!({
+        "items": { "0b7f3a52-9d7e-4c1a-8f11-3c2d4e5f6a7b": legacy },
+        "schema_version": 1,
+    })).unwrap();
+
+    let repo = TeamRepository::with_store(store);
+    let id = "0b7f3a52-9d7e-4c1a-8f11-3c2d4e5f6a7b".parse().unwrap();
+    let project = repo.get_project(id).unwrap().unwrap();
+    assert!(project.status_history.is_empty());
+    assert_eq!(repo.list_projects().unwrap().len(), 1);
+}
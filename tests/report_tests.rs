@@ -0,0 +1,167 @@
+use chrono::{Duration, TimeZone, Utc};
+use timeless::config::MetricsConfig;
+use timeless::models::*;
+use timeless::reports::{Report, ReportFormat, ReportType};
+use timeless::storage::{InMemoryStore, TeamRepository};
+
+#[test]
+fn test_report_type_parsing_and_windows() {
+    assert_eq!("Weekly".parse::<ReportType>().unwrap(), ReportType::Weekly);
+    assert_eq!("sprint".parse::<ReportType>().unwrap().duration(), Duration::days(14));
+    assert_eq!(ReportType::Daily.duration(), Duration::days(1));
+    assert_eq!(ReportType::Monthly.to_string(), "monthly");
+    assert!("quarterly".parse::<ReportType>().is_err());
+}
+
+#[test]
+fn test_gather_covers_only_the_window() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let end = Utc.with_ymd_and_hms(2025, 3, 14, 17, 0, 0).unwrap();
+
+    let john = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let jane = TeamMember::new("Jane Roe".to_string(), "jane@example.com".to_string(), "Designer".to_string());
+    repo.save_team_member(&john).unwrap();
+    repo.save_team_member(&jane).unwrap();
+
+    let mut recent = StatusUpdate::new(john.id, "Finished the login flow".to_string());
+    recent.timestamp = end - Duration::days(2);
+    recent.add_blocker("Waiting on API keys".to_string());
+    recent.add_achievement("Shipped login".to_string());
+    repo.save_status_update(&recent).unwrap();
+
+    let mut old = StatusUpdate::new(jane.id, "Sketching the dashboard".to_string());
+    old.timestamp = end - Duration::days(10);
+    repo.save_status_update(&old).unwrap();
+
+    let weekly = Report::gather(&repo, ReportType::Weekly, "Core", &MetricsConfig::default(), end).unwrap();
+    assert_eq!(weekly.period_start, end - Duration::days(7));
+    assert_eq!(weekly.members.len(), 2);
+    assert_eq!(weekly.members[0].name, "Jane Roe");
+    assert!(weekly.members[0].updates.is_empty());
+    assert_eq!(weekly.members[1].updates.len(), 1);
+    assert_eq!(weekly.blockers.len(), 1);
+    assert_eq!(weekly.blockers[0].member, "John Doe");
+    assert_eq!(weekly.achievements[0].text, "Shipped login");
+
+    let sprint = Report::gather(&repo, ReportType::Sprint, "Core", &MetricsConfig::default(), end).unwrap();
+    assert_eq!(sprint.members.iter().map(|m| m.updates.len()).sum::<usize>(), 2);
+}
+
+#[test]
+fn test_shared_blocker_is_listed_once_without_its_tag() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let end = Utc.with_ymd_and_hms(2025, 3, 14, 17, 0, 0).unwrap();
+    let ann = TeamMember::new("Ann".to_string(), "ann@example.com".to_string(), "Developer".to_string());
+    let bob = TeamMember::new("Bob".to_string(), "bob@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&ann).unwrap();
+    repo.save_team_member(&bob).unwrap();
+    for (member, text, days_ago) in [(&ann, "[high] CI is down", 3), (&ann, "CI is down", 2), (&bob, "ci is  DOWN", 1)] {
+        let mut update = StatusUpdate::new(member.id, "Working".to_string());
+        update.timestamp = end - Duration::days(days_ago);
+        update.add_blocker(text.to_string());
+        repo.save_status_update(&update).unwrap();
+    }
+
+    let report = Report::gather(&repo, ReportType::Weekly, "Core", &MetricsConfig::default(), end).unwrap();
+    assert_eq!(report.blockers.len(), 1);
+    assert_eq!(report.blockers[0].text, "CI is down");
+    assert_eq!(report.blockers[0].member, "Ann, Bob");
+    assert!(report.metrics_summary().contains("Blockers reported: 1"));
+}
+
+#[test]
+fn test_prompt_includes_team_data_and_metrics() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let end = Utc::now();
+    let john = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&john).unwrap();
+    let mut update = StatusUpdate::new(john.id, "Refactoring storage for {team_name} and {metrics}".to_string());
+    update.timestamp = end - Duration::hours(3);
+    update.add_blocker("Flaky CI".to_string());
+    repo.save_status_update(&update).unwrap();
+
+    let prompt = Report::gather(&repo, ReportType::Daily, "Core", &MetricsConfig::default(), end).unwrap().prompt();
+    assert!(prompt.contains("daily"));
+    assert!(prompt.contains("Team: Core"));
+    assert!(prompt.contains("Refactoring storage for {team_name} and {metrics}"));
+    assert!(prompt.contains("Blocker: Flaky CI"));
+    assert_eq!(prompt.matches("Blockers reported: 1").count(), 1);
+    assert!(!prompt.contains("{team_data}"));
+}
+
+fn sample_report() -> Report {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let end = Utc.with_ymd_and_hms(2025, 3, 14, 17, 0, 0).unwrap();
+    let john = TeamMember::new("John <Doe>".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&john).unwrap();
+
+    let mut update = StatusUpdate::new(john.id, "Fixing {members} & templates".to_string());
+    update.timestamp = end - Duration::days(1);
+    update.add_blocker("Waiting on review".to_string());
+    repo.save_status_update(&update).unwrap();
+
+    let mut metrics = TeamMetrics::new(end - Duration::hours(1));
+    metrics.active_members = 1;
+    metrics.average_satisfaction = Some(8.0);
+    metrics.velocity = Some(50.0);
+    repo.save_team_metrics(&metrics).unwrap();
+
+    Report::gather(&repo, ReportType::Weekly, "Core", &MetricsConfig::default(), end).unwrap()
+}
+
+#[test]
+fn test_report_renders_each_format() {
+    let report = sample_report();
+    assert!(report.health_score.is_some());
+    assert_eq!("md".parse::<ReportFormat>().unwrap(), ReportFormat::Markdown);
+    assert!("pdf".parse::<ReportFormat>().is_err());
+
+    let markdown = report.render(ReportFormat::Markdown).unwrap();
+    assert!(markdown.starts_with("# Weekly Report: Core"));
+    assert!(markdown.contains("- **John <Doe>**: Waiting on review (2025-03-13)"));
+    assert!(markdown.contains("| Health score |"));
+    assert!(!markdown.contains("## Summary"));
+
+    let html = report.render(ReportFormat::Html).unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("John &lt;Doe&gt;"));
+    assert!(html.contains("Fixing &#123;members&#125; &amp; templates"));
+    assert!(!html.contains("<link") && !html.contains("<script"));
+
+    let json: serde_json::Value = serde_json::from_str(&report.render(ReportFormat::Json).unwrap()).unwrap();
+    assert_eq!(json["report_type"], "weekly");
+    assert_eq!(json["blockers"][0]["text"], "Waiting on review");
+    assert_eq!(json["metrics"]["active_members"], 1);
+    assert!(json.get("summary").is_none());
+}
+
+#[test]
+fn test_placeholders_in_user_text_are_kept_literally() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    let end = Utc.with_ymd_and_hms(2025, 3, 14, 17, 0, 0).unwrap();
+    let ann = TeamMember::new("Ann".to_string(), "ann@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&ann).unwrap();
+    let mut update = StatusUpdate::new(ann.id, "Renamed {projects} in {title}".to_string());
+    update.timestamp = end - Duration::days(1);
+    update.add_blocker("{achievements}".to_string());
+    repo.save_status_update(&update).unwrap();
+    let report = Report::gather(&repo, ReportType::Weekly, "Core", &MetricsConfig::default(), end).unwrap();
+
+    // Every render is the same, whatever order the values are filled in
+    let markdown = report.to_markdown();
+    for _ in 0..20 {
+        assert_eq!(report.to_markdown(), markdown);
+    }
+    assert!(markdown.contains("- 2025-03-13: Renamed {projects} in {title}"));
+    assert!(markdown.contains("- **Ann**: {achievements} (2025-03-13)"));
+    assert_eq!(markdown.matches("_No projects_").count(), 1);
+    assert_eq!(markdown.matches("# Weekly Report: Core").count(), 1);
+}
+
+#[test]
+fn test_summary_is_included_when_present() {
+    let mut report = sample_report();
+    report.summary = Some("A steady week.".to_string());
+    assert!(report.to_markdown().contains("## Summary\n\nA steady week."));
+    assert!(report.to_html().contains("<p>A steady week.</p>"));
+}
@@ -0,0 +1,58 @@
+use timeless::models::TeamMember;
+use timeless::storage::resolver::{resolve_member, resolve_member_exact, ResolveError};
+
+fn team() -> Vec<TeamMember> {
+    let member = |name: &str, email: &str| TeamMember::new(name.to_string(), email.to_string(), "Developer".to_string());
+    vec![
+        member("John Doe", "john@example.com").with_slack_id("U012AB3CD".to_string()),
+        member("Johnny Smith", "johnny@example.com"),
+        member("Jane Roe", "jane@example.com").with_slack_id("@jane".to_string()),
+    ]
+}
+
+fn name_of(members: &[TeamMember], query: &str) -> String {
+    resolve_member(members, query).unwrap().name.clone()
+}
+
+#[test]
+fn test_exact_identifiers() {
+    let team = team();
+    assert_eq!(name_of(&team, &team[1].id.to_string()), "Johnny Smith");
+    assert_eq!(name_of(&team, &team[1].id.to_string()[..8]), "Johnny Smith");
+    assert_eq!(name_of(&team, "JOHN@example.com"), "John Doe");
+    assert_eq!(name_of(&team, "u012ab3cd"), "John Doe");
+    assert_eq!(name_of(&team, "jane"), "Jane Roe");
+    assert_eq!(name_of(&team, "john doe"), "John Doe");
+}
+
+#[test]
+fn test_fuzzy_names() {
+    let team = team();
+    assert_eq!(name_of(&team, "smith"), "Johnny Smith");
+    assert_eq!(name_of(&team, "jo do"), "John Doe");
+    assert_eq!(name_of(&team, "Jnae Roe"), "Jane Roe");
+    assert!(matches!(resolve_member(&team, "zed"), Err(ResolveError::NotFound(_))));
+}
+
+#[test]
+fn test_ambiguous_names_list_candidates() {
+    let team = team();
+    let err = resolve_member(&team, "john").unwrap_err();
+    let ResolveError::Ambiguous { candidates, .. } = &err else {
+        panic!("expected an ambiguity error, got {}", err);
+    };
+    let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["John Doe", "Johnny Smith"]);
+    assert!(err.to_string().contains("john@example.com"));
+}
+
+#[test]
+fn test_exact_resolution_ignores_names_and_typos() {
+    let team = team();
+    assert_eq!(resolve_member_exact(&team, "JANE@example.com").unwrap().name, "Jane Roe");
+    assert_eq!(resolve_member_exact(&team, &team[1].id.to_string()[..8]).unwrap().name, "Johnny Smith");
+    assert_eq!(resolve_member_exact(&team, &team[0].id.to_string()).unwrap().name, "John Doe");
+    for query in ["John Doe", "Jnae Roe", "U012AB3CD", "jane", "abc"] {
+        assert!(matches!(resolve_member_exact(&team, query), Err(ResolveError::NotFound(_))), "{}", query);
+    }
+}
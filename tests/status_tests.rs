@@ -0,0 +1,91 @@
+use std::io::Cursor;
+use timeless::cli::status::{prompt_status_update, Prompter, StatusSubmission};
+use timeless::models::*;
+
+fn member() -> TeamMember {
+    TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string())
+}
+
+#[test]
+fn test_prompt_builds_full_update() {
+    let input = "Finishing the API\ngreat\nWaiting on review\n\nShipped login\nFixed CI\n\n";
+    let mut output = Vec::new();
+    let mut prompter = Prompter::new(Cursor::new(input), &mut output);
+    let member = member();
+
+    let update = prompt_status_update(&mut prompter, &member, None).unwrap().unwrap();
+    assert_eq!(update.member_id, member.id);
+    assert_eq!(update.content, "Finishing the API");
+    assert_eq!(update.mood.as_deref(), Some("great"));
+    assert_eq!(update.blockers, vec!["Waiting on review"]);
+    assert_eq!(update.achievements, vec!["Shipped login", "Fixed CI"]);
+    assert!(!prompter.is_closed());
+
+    let shown = String::from_utf8(output).unwrap();
+    assert!(shown.contains("No previous updates"));
+}
+
+#[test]
+fn test_prompt_shows_last_update_and_skips_empty_content() {
+    let member = member();
+    let last = StatusUpdate::new(member.id, "Planning sprint".to_string()).with_mood("ok".to_string());
+
+    let mut output = Vec::new();
+    let mut prompter = Prompter::new(Cursor::new("\n"), &mut output);
+    assert!(prompt_status_update(&mut prompter, &member, Some(&last)).unwrap().is_none());
+    assert!(!prompter.is_closed());
+
+    let shown = String::from_utf8(output).unwrap();
+    assert!(shown.contains("Planning sprint"));
+}
+
+#[test]
+fn test_prompt_stops_at_end_of_input() {
+    let member = member();
+    let mut prompter = Prompter::new(Cursor::new("Half an answer\n"), Vec::new());
+
+    // Optional questions after end of input are treated as unanswered
+    let update = prompt_status_update(&mut prompter, &member, None).unwrap().unwrap();
+    assert!(update.mood.is_none() && update.blockers.is_empty());
+    assert!(prompter.is_closed());
+    assert!(prompt_status_update(&mut prompter, &member, None).unwrap().is_none());
+}
+
+#[test]
+fn test_submission_parses_json_and_key_values() {
+    let json = r#"{ "member": "john@example.com", "content": "API work", "blockers": ["CI down"] }"#;
+    let submission = StatusSubmission::parse(json).unwrap();
+    assert_eq!(submission.member.as_deref(), Some("john@example.com"));
+    assert_eq!(submission.blockers, vec!["CI down"]);
+
+    let yaml = "# daily update\nmember: john@example.com\ncontent: \"API work: auth\"\nmood: good\nblockers:\n  - CI down\n  - Waiting on design\nachievements: Shipped login\n";
+    let submission = StatusSubmission::parse(yaml).unwrap();
+    assert_eq!(submission.content, "API work: auth");
+    assert_eq!(submission.blockers, vec!["CI down", "Waiting on design"]);
+    assert_eq!(submission.achievements, vec!["Shipped login"]);
+
+    let update = submission.into_update(&member()).unwrap();
+    assert_eq!(update.mood.as_deref(), Some("good"));
+    assert!(update.has_blockers());
+}
+
+#[test]
+fn test_submission_validation() {
+    assert!(StatusSubmission::parse(r#"{ "content": "x", "colour": "blue" }"#).is_err());
+    assert!(StatusSubmission::parse("content: x\n  - stray item").is_err());
+
+    let err = StatusSubmission::parse("content:\nmood: good").unwrap_err();
+    assert_eq!(err.to_string(), "line 1: content has no value");
+    let err = StatusSubmission::parse(r#"{ "content": ["x"], "mood": "good" }"#).unwrap_err();
+    assert_eq!(err.to_string(), "invalid content in status update");
+    let err = StatusSubmission::parse("content: x\ntimestamp: yesterday").unwrap_err();
+    assert_eq!(err.to_string(), "invalid timestamp in status update");
+    let empty_lists = StatusSubmission::parse("content: x\nblockers:\nachievements:").unwrap();
+    assert!(empty_lists.blockers.is_empty() && empty_lists.achievements.is_empty());
+
+    let empty = StatusSubmission::parse("content: \"  \"").unwrap();
+    assert!(empty.into_update(&member()).is_err());
+
+    let future = StatusSubmission::parse(r#"{ "content": "x", "timestamp": "2999-01-01T00:00:00Z" }"#).unwrap();
+    assert!(future.into_update(&member()).is_err());
+}
@@ -1,5 +1,9 @@
+use timeless::config::StorageConfig;
 use timeless::models::*;
-use timeless::storage::TeamRepository;
+use timeless::storage::{
+    InMemoryStore, JsonStore, RepositoryError, SqliteStore, Storage, StorageBackend, StorageError, TeamRepository,
+};
+use timeless::storage::json_store::DataCollection;
 use tempfile::TempDir;
 use uuid::Uuid;
 
@@ -119,8 +123,8 @@ async fn test_team_metrics() {
     metrics.active_members = 5;
     metrics.completed_tasks = 20;
     metrics.blockers_count = 1;
-    metrics.average_satisfaction = 8.0;
-    metrics.velocity = 80.0;
+    metrics.average_satisfaction = Some(8.0);
+    metrics.velocity = Some(80.0);
     
     // Save metrics
     repo.save_team_metrics(&metrics).unwrap();
@@ -137,8 +141,8 @@ async fn test_team_metrics() {
     
     // Test concerning metrics
     let mut concerning_metrics = TeamMetrics::new(chrono::Utc::now());
-    concerning_metrics.average_satisfaction = 3.0; // Low satisfaction
-    concerning_metrics.velocity = 5.0; // Low velocity
+    concerning_metrics.average_satisfaction = Some(3.0); // Low satisfaction
+    concerning_metrics.velocity = Some(5.0); // Low velocity
     
     assert!(concerning_metrics.has_concerning_metrics());
 }
@@ -169,4 +173,247 @@ async fn test_ai_decisions() {
     let recent_decisions = repo.get_recent_ai_decisions(5).unwrap();
     assert_eq!(recent_decisions.len(), 1);
     assert!(recent_decisions[0].outcome.is_some());
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_atomic_save_leaves_no_temp_files() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = TeamRepository::new(temp_dir.path()).unwrap();
+    
+    let member = TeamMember::new("Jane Roe".to_string(), "jane@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&member).unwrap();
+    repo.save_team_member(&member).unwrap();
+    
+    let mut files: Vec<String> = std::fs::read_dir(temp_dir.path()).unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    files.sort();
+    assert_eq!(files, vec!["team_members.json", "team_members.json.bak", "team_members.lock"]);
+}
+
+#[tokio::test]
+async fn test_corrupt_file_recovers_from_backup() {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = TeamRepository::new(temp_dir.path()).unwrap();
+    
+    let first = TeamMember::new("Jane Roe".to_string(), "jane@example.com".to_string(), "Developer".to_string());
+    let second = TeamMember::new("Max Mustermann".to_string(), "max@example.com".to_string(), "Designer".to_string());
+    repo.save_team_member(&first).unwrap();
+    repo.save_team_member(&second).unwrap();
+    
+    // Simulate a write that was interrupted half-way through
+    let path = temp_dir.path().join("team_members.json");
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, &contents[..contents.len() / 2]).unwrap();
+    
+    // The backup holds the state before the last save
+    let members = repo.list_team_members().unwrap();
+    assert_eq!(members.len(), 1);
+    assert_eq!(members[0].id, first.id);
+    
+    // The primary file has been restored and is readable again
+    let restored: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert!(restored.get("items").is_some());
+}
+
+#[tokio::test]
+async fn test_corrupt_file_without_backup_is_an_error() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = JsonStore::new(temp_dir.path()).unwrap();
+    
+    std::fs::write(temp_dir.path().join("projects.json"), "{ \"items\": ").unwrap();
+    let result: Result<Option<serde_json::Value>, StorageError> = store.load("projects");
+    assert!(matches!(result, Err(StorageError::Corrupt { .. })));
+}
+
+#[tokio::test]
+async fn test_concurrent_saves_do_not_lose_writes() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().to_path_buf();
+    
+    let handles: Vec<_> = (0..4).map(|worker| {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            // Each thread opens its own repository, like separate processes would
+            let repo = TeamRepository::new(&path).unwrap();
+            for i in 0..5 {
+                let member = TeamMember::new(
+                    format!("Worker {} #{}", worker, i),
+                    format!("worker{}-{}@example.com", worker, i),
+                    "Developer".to_string(),
+                );
+                repo.save_team_member(&member).unwrap();
+            }
+        })
+    }).collect();
+    
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    
+    let repo = TeamRepository::new(&path).unwrap();
+    assert_eq!(repo.list_team_members().unwrap().len(), 20);
+}
+
+#[tokio::test]
+async fn test_concurrent_saves_cannot_duplicate_an_email() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().to_path_buf();
+
+    let handles: Vec<_> = (0..4).map(|worker| {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            let repo = TeamRepository::new(&path).unwrap();
+            let member = TeamMember::new(
+                format!("Worker {}", worker),
+                "shared@example.com".to_string(),
+                "Developer".to_string(),
+            );
+            repo.save_team_member(&member)
+        })
+    }).collect();
+
+    let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+    assert!(results.iter().filter_map(|r| r.as_ref().err()).all(|e| matches!(e, RepositoryError::Member(_))));
+
+    let repo = TeamRepository::new(&path).unwrap();
+    assert_eq!(repo.list_team_members().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_concurrent_updates_open_a_shared_blocker_once() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().to_path_buf();
+
+    let handles: Vec<_> = (0..4).map(|_| {
+        let path = path.clone();
+        std::thread::spawn(move || {
+            let repo = TeamRepository::new(&path).unwrap();
+            let mut update = StatusUpdate::new(Uuid::new_v4(), "Stuck".to_string());
+            update.add_blocker("Staging is down".to_string());
+            repo.save_status_update(&update).unwrap();
+        })
+    }).collect();
+
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let repo = TeamRepository::new(&path).unwrap();
+    let blockers = repo.list_blockers().unwrap();
+    assert_eq!(blockers.len(), 1);
+    assert_eq!(blockers[0].reporters().count(), 4);
+}
+
+#[tokio::test]
+async fn test_lock_timeout_is_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = JsonStore::new(temp_dir.path()).unwrap()
+        .with_lock_timeout(std::time::Duration::from_millis(50));
+    
+    // Hold the lock from a separate handle for the duration of the update
+    let lock = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(temp_dir.path().join("projects.lock"))
+        .unwrap();
+    lock.lock().unwrap();
+    
+    let result = store.update("projects", |_: &mut serde_json::Value| Ok(()));
+    let err = result.unwrap_err();
+    assert!(matches!(err, StorageError::LockTimeout { .. }));
+    assert!(err.to_string().contains("timed out"));
+}
+
+#[tokio::test]
+async fn test_sqlite_backend_repository() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = SqliteStore::new(temp_dir.path()).unwrap();
+    let repo = TeamRepository::with_store(store);
+    
+    let member = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    let other = TeamMember::new("Jane Roe".to_string(), "jane@example.com".to_string(), "Designer".to_string());
+    repo.save_team_member(&member).unwrap();
+    repo.save_team_member(&other).unwrap();
+    assert_eq!(repo.list_team_members().unwrap().len(), 2);
+    assert_eq!(repo.get_team_member(member.id).unwrap().unwrap().email, "john@example.com");
+    
+    repo.save_status_update(&StatusUpdate::new(member.id, "Feature A".to_string())).unwrap();
+    repo.save_status_update(&StatusUpdate::new(member.id, "Feature B".to_string())).unwrap();
+    repo.save_status_update(&StatusUpdate::new(other.id, "Mockups".to_string())).unwrap();
+    assert_eq!(repo.get_status_updates_for_member(member.id).unwrap().len(), 2);
+    assert_eq!(repo.get_recent_status_updates(2).unwrap().len(), 2);
+    
+    let now = chrono::Utc::now();
+    for days in [3, 1, 10] {
+        repo.save_team_metrics(&TeamMetrics::new(now - chrono::Duration::days(days))).unwrap();
+    }
+    let range = repo.get_team_metrics_range(now - chrono::Duration::days(5), now).unwrap();
+    assert_eq!(range.len(), 2);
+    assert!(range[0].date < range[1].date);
+    assert!(repo.get_latest_team_metrics().unwrap().unwrap().date > range[0].date);
+    
+    assert!(repo.remove_team_member(other.id).unwrap().is_some());
+    assert!(repo.remove_team_member(other.id).unwrap().is_none());
+    assert_eq!(repo.list_team_members().unwrap().len(), 1);
+}
+
+#[test]
+fn test_backend_rejects_unknown_format() {
+    let temp_dir = TempDir::new().unwrap();
+    let config = StorageConfig { data_format: "yaml".to_string(), ..StorageConfig::default() };
+    match StorageBackend::from_config(&config, temp_dir.path()) {
+        Err(StorageError::UnsupportedFormat(format)) => assert_eq!(format, "yaml"),
+        Err(other) => panic!("unexpected error: {}", other),
+        Ok(_) => panic!("yaml should not open a store"),
+    }
+}
+
+#[tokio::test]
+async fn test_sqlite_store_roundtrips_collections_and_documents() {
+    let temp_dir = TempDir::new().unwrap();
+    let store = SqliteStore::new(temp_dir.path()).unwrap();
+    
+    let mut projects: DataCollection<Project> = DataCollection::new();
+    let project = Project::new("Apollo".to_string(), "Moonshot".to_string());
+    projects.insert(project.id.to_string(), project.clone());
+    store.save("projects", &projects).unwrap();
+    
+    let document = serde_json::json!({ "team": "Platform", "sprint": 12 });
+    store.save("settings", &document).unwrap();
+    
+    let loaded: DataCollection<Project> = store.load("projects").unwrap().unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded.get(&project.id.to_string()).unwrap().name, "Apollo");
+    assert_eq!(store.load::<serde_json::Value>("settings").unwrap().unwrap(), document);
+    
+    let mut keys = store.list_keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["projects", "settings"]);
+    
+    store.delete("projects").unwrap();
+    assert!(store.load::<DataCollection<Project>>("projects").unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_in_memory_repository() {
+    let repo = TeamRepository::with_store(InMemoryStore::new());
+    
+    let member = TeamMember::new("John Doe".to_string(), "john@example.com".to_string(), "Developer".to_string());
+    repo.save_team_member(&member).unwrap();
+    
+    let mut status = StatusUpdate::new(member.id, "Working on feature A".to_string());
+    status.add_blocker("Waiting for API".to_string());
+    repo.save_status_update(&status).unwrap();
+    
+    assert_eq!(repo.get_team_member(member.id).unwrap().unwrap().name, "John Doe");
+    let updates = repo.get_status_updates_for_member(member.id).unwrap();
+    assert_eq!(updates.len(), 1);
+    assert!(updates[0].has_blockers());
+    
+    let mut keys = repo.store().list_keys().unwrap();
+    keys.sort();
+    assert_eq!(keys, vec!["blockers", "status_updates", "team_members"]);
+}
@@ -0,0 +1,96 @@
+use chrono::{Duration, NaiveDate, TimeZone, Utc};
+use timeless::metrics::trends::{analyze, anomalies, moving_average, sparkline, week_over_week, weekly_buckets, Series};
+use timeless::models::*;
+
+fn snapshot(date: NaiveDate, velocity: f32, satisfaction: f32, active: u32, blockers: u32) -> TeamMetrics {
+    let mut metrics = TeamMetrics::new(Utc.from_utc_datetime(&date.and_hms_opt(12, 0, 0).unwrap()));
+    metrics.velocity = Some(velocity);
+    metrics.average_satisfaction = Some(satisfaction);
+    metrics.active_members = active;
+    metrics.blockers_count = blockers;
+    metrics
+}
+
+fn monday() -> NaiveDate {
+    NaiveDate::from_ymd_opt(2025, 3, 10).unwrap()
+}
+
+#[test]
+fn test_weekly_buckets_average_and_prefer_weekly_snapshots() {
+    let monday = monday();
+    let mut weekly = snapshot(monday, 0.0, 6.0, 4, 1);
+    weekly.period = Some(MetricsPeriod::Weekly);
+    // Computed snapshots have no velocity
+    weekly.velocity = None;
+    let metrics = vec![
+        // Given out of order on purpose
+        snapshot(monday + Duration::days(2), 30.0, 8.0, 4, 2),
+        snapshot(monday, 10.0, 6.0, 2, 2),
+        snapshot(monday - Duration::days(5), 20.0, 7.0, 4, 0),
+        weekly,
+    ];
+
+    let buckets = weekly_buckets(&metrics, monday + Duration::days(4), 3);
+    assert_eq!(buckets.len(), 3);
+    assert_eq!(buckets[0].snapshots, 0);
+    assert_eq!(buckets[0].velocity, None);
+    assert_eq!(buckets[1].week_start, monday - Duration::days(7));
+    assert_eq!(buckets[1].velocity, Some(20.0));
+    assert_eq!(buckets[2].week_start, monday);
+    assert_eq!(buckets[2].snapshots, 1);
+    assert_eq!(buckets[2].velocity, None);
+    assert_eq!(buckets[2].satisfaction, Some(6.0));
+    assert_eq!(buckets[2].blockers_per_member, Some(0.25));
+
+    let mut daily_only: Vec<TeamMetrics> = metrics.into_iter().filter(|m| m.period.is_none()).collect();
+    // A day without moods doesn't drag satisfaction down
+    let mut moodless = snapshot(monday + Duration::days(1), 20.0, 0.0, 4, 3);
+    moodless.average_satisfaction = None;
+    daily_only.push(moodless);
+    let buckets = weekly_buckets(&daily_only, monday, 1);
+    assert_eq!(buckets[0].velocity, Some(20.0));
+    assert_eq!(buckets[0].satisfaction, Some(7.0));
+    assert_eq!(buckets[0].blockers_per_member, Some(0.75));
+}
+
+#[test]
+fn test_moving_average_and_deltas() {
+    let values = [Some(10.0), None, Some(20.0), Some(30.0), Some(40.0)];
+    assert_eq!(moving_average(&values, 2), vec![Some(10.0), None, Some(15.0), Some(25.0), Some(35.0)]);
+    assert_eq!(week_over_week(&values), vec![None, None, None, Some(10.0), Some(10.0)]);
+}
+
+#[test]
+fn test_anomalies_flag_drops_in_the_bad_direction() {
+    let steady = [Some(50.0), Some(52.0), Some(48.0), Some(51.0), Some(20.0)];
+    assert_eq!(anomalies(&steady, true), vec![4]);
+    assert!(anomalies(&steady, false).is_empty());
+
+    // A perfectly flat history still flags a clear change
+    let flat = [Some(1.0), Some(1.0), Some(1.0), Some(2.0)];
+    assert_eq!(anomalies(&flat, false), vec![3]);
+    // Not enough history yet
+    assert!(anomalies(&[Some(50.0), Some(50.0), Some(5.0)], true).is_empty());
+}
+
+#[test]
+fn test_analyze_and_sparkline() {
+    let monday = monday();
+    let metrics: Vec<TeamMetrics> = [40.0, 42.0, 41.0, 43.0, 10.0]
+        .iter()
+        .enumerate()
+        .map(|(i, &velocity)| snapshot(monday - Duration::weeks(4 - i as i64), velocity, 7.0, 4, 1))
+        .collect();
+
+    let trends = analyze(&metrics, monday, 6, 3);
+    assert_eq!(trends.weeks.len(), 6);
+    let velocity = trends.series.iter().find(|s| s.series == Series::Velocity).unwrap();
+    assert_eq!(velocity.latest(), Some(10.0));
+    assert_eq!(velocity.week_over_week[5], Some(-33.0));
+    assert_eq!(velocity.anomalies, vec![5]);
+    assert_eq!(velocity.sparkline().chars().count(), 6);
+    assert!(velocity.sparkline().starts_with(' '));
+
+    assert_eq!(sparkline(&[Some(0.0), Some(7.0), None]), "▁█ ");
+    assert_eq!(sparkline(&[Some(3.0), Some(3.0)]), "▅▅");
+}